@@ -0,0 +1,189 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::StatusCode;
+
+use crate::backends::{BackendCapabilities, SearchBackend};
+use crate::error::WebSearchError;
+use crate::http_client::send;
+use crate::models::brave::BraveResponse;
+use crate::models::search::{
+    region_locale, SearchOptions, SearchResponse, SearchResult, TimeRange,
+};
+use crate::rate_limiter::RateLimiter;
+
+const ATTRIBUTION: &str = "Brave Search";
+const PAGE_SIZE: usize = 20;
+const MAX_OFFSET: usize = 9;
+
+#[derive(Clone)]
+pub struct BraveBackend {
+    client: reqwest::Client,
+    base_url: String,
+    api_key: String,
+    rate_limiter: Arc<RateLimiter>,
+    timeout_secs: u64,
+}
+
+impl std::fmt::Debug for BraveBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BraveBackend")
+            .field("base_url", &self.base_url)
+            .field("rate_limiter", &self.rate_limiter)
+            .finish()
+    }
+}
+
+impl BraveBackend {
+    pub fn new(
+        client: reqwest::Client,
+        base_url: String,
+        api_key: String,
+        rate_limiter: Arc<RateLimiter>,
+        timeout_secs: u64,
+    ) -> Self {
+        Self {
+            client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_key,
+            rate_limiter,
+            timeout_secs,
+        }
+    }
+
+    async fn fetch_page(
+        &self,
+        query: &str,
+        options: &SearchOptions,
+        offset: usize,
+    ) -> Result<BraveResponse, WebSearchError> {
+        let mut params = vec![
+            ("q", query.to_string()),
+            ("count", PAGE_SIZE.to_string()),
+            ("offset", offset.to_string()),
+            ("safesearch", options.safe_search.as_str().to_string()),
+        ];
+        if let Some((_, country)) = options.region.as_deref().and_then(region_locale) {
+            params.push(("country", country));
+        }
+        if let Some(time_range) = options.time_range {
+            params.push(("freshness", freshness_value(time_range).to_string()));
+        }
+
+        self.rate_limiter.acquire().await;
+        let response = send(
+            self.client
+                .get(format!("{}/res/v1/web/search", self.base_url))
+                .header("Accept", "application/json")
+                .header("X-Subscription-Token", &self.api_key)
+                .query(&params),
+            self.timeout_secs,
+        )
+        .await?;
+
+        match response.status() {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                return Err(WebSearchError::Unauthorized(ATTRIBUTION.to_string()))
+            }
+            StatusCode::PAYMENT_REQUIRED => {
+                return Err(WebSearchError::PaymentRequired(ATTRIBUTION.to_string()))
+            }
+            StatusCode::TOO_MANY_REQUESTS => {
+                return Err(WebSearchError::RateLimited(ATTRIBUTION.to_string()))
+            }
+            _ => {}
+        }
+
+        let body = response.error_for_status()?.text().await?;
+        Ok(serde_json::from_str(&body)?)
+    }
+}
+
+fn freshness_value(time_range: TimeRange) -> &'static str {
+    match time_range {
+        TimeRange::Day => "pd",
+        TimeRange::Week => "pw",
+        TimeRange::Month => "pm",
+        TimeRange::Year => "py",
+    }
+}
+
+#[async_trait]
+impl SearchBackend for BraveBackend {
+    fn name(&self) -> &str {
+        "brave"
+    }
+
+    fn attribution(&self) -> &str {
+        ATTRIBUTION
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            pagination: true,
+            region: true,
+            safe_search: true,
+            time_range: true,
+        }
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        options: &SearchOptions,
+    ) -> Result<SearchResponse, WebSearchError> {
+        if query.is_empty() {
+            return Err(WebSearchError::EmptyQuery);
+        }
+
+        let query = options.query_with_site_operators(query);
+        let mut results = Vec::new();
+
+        for offset in options.page.max(1) - 1..=MAX_OFFSET {
+            if results.len() >= options.max_results {
+                break;
+            }
+            let page = self.fetch_page(&query, options, offset).await?;
+            let page_results = page.web.map(|web| web.results).unwrap_or_default();
+            let page_len = page_results.len();
+            let remaining = options.max_results - results.len();
+            results.extend(
+                page_results
+                    .into_iter()
+                    .map(SearchResult::from)
+                    .filter(|result| options.allows_url(&result.url))
+                    .take(remaining),
+            );
+            if page_len < PAGE_SIZE {
+                break;
+            }
+        }
+
+        Ok(results.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_freshness_values() {
+        assert_eq!(freshness_value(TimeRange::Day), "pd");
+        assert_eq!(freshness_value(TimeRange::Week), "pw");
+        assert_eq!(freshness_value(TimeRange::Month), "pm");
+        assert_eq!(freshness_value(TimeRange::Year), "py");
+    }
+
+    #[test]
+    fn test_debug_does_not_leak_api_key() {
+        let backend = BraveBackend::new(
+            reqwest::Client::new(),
+            "https://api.search.brave.com".to_string(),
+            "secret-token".to_string(),
+            Arc::new(RateLimiter::new(std::time::Duration::from_millis(0))),
+            10,
+        );
+        assert!(!format!("{backend:?}").contains("secret-token"));
+    }
+}
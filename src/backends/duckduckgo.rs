@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::backends::{BackendCapabilities, SearchBackend};
+use crate::error::WebSearchError;
+use crate::models::search::{SearchOptions, SearchResponse};
+use crate::rate_limiter::RateLimiter;
+use crate::tools::web_search::search_web;
+
+pub const DUCKDUCKGO_HTML_BASE_URL: &str = "https://html.duckduckgo.com";
+pub const DUCKDUCKGO_LITE_BASE_URL: &str = "https://lite.duckduckgo.com";
+
+#[derive(Debug, Clone)]
+pub struct DuckDuckGoBackend {
+    client: reqwest::Client,
+    base_url: String,
+    lite_base_url: String,
+    rate_limiter: Arc<RateLimiter>,
+    timeout_secs: u64,
+}
+
+impl DuckDuckGoBackend {
+    pub fn new(
+        client: reqwest::Client,
+        base_url: String,
+        lite_base_url: String,
+        rate_limiter: Arc<RateLimiter>,
+        timeout_secs: u64,
+    ) -> Self {
+        Self {
+            client,
+            base_url,
+            lite_base_url,
+            rate_limiter,
+            timeout_secs,
+        }
+    }
+}
+
+#[async_trait]
+impl SearchBackend for DuckDuckGoBackend {
+    fn name(&self) -> &str {
+        "duckduckgo"
+    }
+
+    fn attribution(&self) -> &str {
+        "DuckDuckGo"
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            pagination: true,
+            region: true,
+            safe_search: true,
+            time_range: true,
+        }
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        options: &SearchOptions,
+    ) -> Result<SearchResponse, WebSearchError> {
+        self.rate_limiter.acquire().await;
+        let results = search_web(
+            &self.client,
+            &self.rate_limiter,
+            &self.base_url,
+            &self.lite_base_url,
+            query,
+            options,
+            self.timeout_secs,
+        )
+        .await?;
+        Ok(results.into())
+    }
+}
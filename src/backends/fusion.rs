@@ -0,0 +1,290 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use reqwest::Url;
+use tokio::task::JoinSet;
+
+use crate::backends::{search_backend, SearchBackend};
+use crate::error::WebSearchError;
+use crate::models::search::{SearchOptions, SearchResponse, SearchResult};
+use crate::retry::retry_with_backoff;
+
+const RRF_K: f64 = 60.0;
+
+#[derive(Debug, Clone)]
+pub struct FusedSearch {
+    pub response: SearchResponse,
+    pub attribution: String,
+}
+
+pub fn normalize_url(url: &str) -> String {
+    let Ok(mut parsed) = Url::parse(url.trim()) else {
+        return url.trim().to_ascii_lowercase();
+    };
+    parsed.set_fragment(None);
+
+    let pairs: Vec<(String, String)> = parsed
+        .query_pairs()
+        .filter(|(key, _)| !key.starts_with("utm_"))
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+    if pairs.is_empty() {
+        parsed.set_query(None);
+    } else {
+        parsed.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+
+    let host = parsed.host_str().unwrap_or_default().to_ascii_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+    let path = parsed.path().trim_end_matches('/');
+    match parsed.query() {
+        Some(query) => format!("{host}{path}?{query}"),
+        None => format!("{host}{path}"),
+    }
+}
+
+pub fn reciprocal_rank_fusion(
+    responses: Vec<(String, SearchResponse)>,
+    max_results: usize,
+) -> SearchResponse {
+    let mut fused: Vec<(f64, SearchResult)> = Vec::new();
+    let mut index_by_url: HashMap<String, usize> = HashMap::new();
+    let mut merged = SearchResponse::default();
+    let mut ads = Vec::new();
+
+    for (backend, response) in responses {
+        // Ads neither take part in the ranking nor count toward `max_results`.
+        let (backend_ads, organic): (Vec<SearchResult>, Vec<SearchResult>) = response
+            .results
+            .into_iter()
+            .partition(|result| result.is_ad);
+        ads.extend(backend_ads.into_iter().map(|mut ad| {
+            ad.sources = vec![backend.clone()];
+            ad
+        }));
+        for (rank, result) in organic.into_iter().enumerate() {
+            let score = 1.0 / (RRF_K + rank as f64 + 1.0);
+            let key = normalize_url(&result.url);
+            match index_by_url.get(&key) {
+                Some(&index) => {
+                    let (total, existing) = &mut fused[index];
+                    *total += score;
+                    if !existing.sources.contains(&backend) {
+                        existing.sources.push(backend.clone());
+                    }
+                    if existing.snippet.is_empty() {
+                        existing.snippet = result.snippet;
+                    }
+                    if existing.date.is_none() {
+                        existing.date = result.date;
+                    }
+                    for engine in result.engines {
+                        if !existing.engines.contains(&engine) {
+                            existing.engines.push(engine);
+                        }
+                    }
+                }
+                None => {
+                    let mut result = result;
+                    result.sources = vec![backend.clone()];
+                    index_by_url.insert(key, fused.len());
+                    fused.push((score, result));
+                }
+            }
+        }
+        merged.infoboxes.extend(response.infoboxes);
+        for suggestion in response.suggestions {
+            if !merged.suggestions.contains(&suggestion) {
+                merged.suggestions.push(suggestion);
+            }
+        }
+        merged.warnings.extend(response.warnings);
+    }
+
+    // Stable sort keeps first-seen order for equal scores.
+    fused.sort_by(|a, b| b.0.total_cmp(&a.0));
+    merged.results = fused
+        .into_iter()
+        .map(|(_, result)| result)
+        .take(max_results)
+        .chain(ads)
+        .collect();
+    merged
+}
+
+pub async fn search_all(
+    backends: &[Arc<dyn SearchBackend>],
+    query: &str,
+    options: &SearchOptions,
+    max_retries: u32,
+) -> Result<FusedSearch, WebSearchError> {
+    let mut tasks = JoinSet::new();
+    for (index, backend) in backends.iter().enumerate() {
+        let backend = backend.clone();
+        let query = query.to_string();
+        let options = options.clone();
+        tasks.spawn(async move {
+            let result = retry_with_backoff(max_retries, || {
+                let backend = backend.clone();
+                let query = query.clone();
+                let options = options.clone();
+                async move { search_backend(backend.as_ref(), &query, &options).await }
+            })
+            .await;
+            (index, result)
+        });
+    }
+
+    let mut outcomes: Vec<Option<Result<SearchResponse, WebSearchError>>> =
+        backends.iter().map(|_| None).collect();
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok((index, result)) = joined {
+            outcomes[index] = Some(result);
+        }
+    }
+
+    let mut responses = Vec::new();
+    let mut attributions = Vec::new();
+    let mut warnings = Vec::new();
+    let mut first_error = None;
+    for (backend, outcome) in backends.iter().zip(outcomes) {
+        match outcome {
+            Some(Ok(response)) => {
+                attributions.push(backend.attribution().to_string());
+                responses.push((backend.name().to_string(), response));
+            }
+            Some(Err(e)) => {
+                tracing::warn!(backend = backend.name(), error = %e, "Search backend failed");
+                warnings.push(format!("{} failed: {}", backend.name(), e.user_message()));
+                first_error.get_or_insert(e);
+            }
+            None => {
+                warnings.push(format!("{} failed: search task aborted", backend.name()));
+            }
+        }
+    }
+
+    if responses.is_empty() {
+        return Err(first_error.unwrap_or(WebSearchError::NoResults(query.to_string())));
+    }
+
+    let mut response = reciprocal_rank_fusion(responses, options.max_results);
+    response.warnings.extend(warnings);
+    Ok(FusedSearch {
+        response,
+        attribution: attributions.join(", "),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(url: &str) -> SearchResult {
+        SearchResult {
+            title: url.to_string(),
+            url: url.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn response(urls: &[&str]) -> SearchResponse {
+        urls.iter()
+            .map(|url| result(url))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    #[test]
+    fn test_normalize_url_ignores_cosmetic_differences() {
+        assert_eq!(
+            normalize_url("https://www.Example.com/docs/?utm_source=x#intro"),
+            normalize_url("http://example.com/docs")
+        );
+        assert_ne!(
+            normalize_url("https://example.com/docs?page=1"),
+            normalize_url("https://example.com/docs?page=2")
+        );
+    }
+
+    #[test]
+    fn test_rrf_ranks_shared_results_first() {
+        let fused = reciprocal_rank_fusion(
+            vec![
+                (
+                    "a".to_string(),
+                    response(&["https://one.example/", "https://shared.example/"]),
+                ),
+                (
+                    "b".to_string(),
+                    response(&["https://two.example/", "https://www.shared.example"]),
+                ),
+            ],
+            10,
+        );
+        assert_eq!(fused.results.len(), 3);
+        assert_eq!(fused.results[0].url, "https://shared.example/");
+        assert_eq!(fused.results[0].sources, vec!["a", "b"]);
+        assert_eq!(fused.results[1].sources, vec!["a"]);
+        assert_eq!(fused.results[2].sources, vec!["b"]);
+    }
+
+    #[test]
+    fn test_rrf_respects_max_results() {
+        let fused = reciprocal_rank_fusion(
+            vec![(
+                "a".to_string(),
+                response(&[
+                    "https://1.example/",
+                    "https://2.example/",
+                    "https://3.example/",
+                ]),
+            )],
+            2,
+        );
+        assert_eq!(fused.results.len(), 2);
+    }
+
+    #[test]
+    fn test_rrf_keeps_ads_out_of_ranking() {
+        let ad = SearchResult {
+            is_ad: true,
+            ..result("https://shared.example/")
+        };
+        let first = SearchResponse::from(vec![
+            ad.clone(),
+            result("https://one.example/"),
+            result("https://shared.example/"),
+        ]);
+        let second = response(&["https://shared.example/", "https://two.example/"]);
+
+        let fused =
+            reciprocal_rank_fusion(vec![("a".to_string(), first), ("b".to_string(), second)], 2);
+        let organic: Vec<&SearchResult> = fused.results.iter().filter(|r| !r.is_ad).collect();
+        assert_eq!(organic.len(), 2);
+        assert_eq!(organic[0].url, "https://shared.example/");
+        assert_eq!(organic[0].sources, vec!["a", "b"]);
+        assert_eq!(organic[1].url, "https://one.example/");
+        let ads: Vec<&SearchResult> = fused.results.iter().filter(|r| r.is_ad).collect();
+        assert_eq!(ads.len(), 1);
+        assert_eq!(ads[0].sources, vec!["a"]);
+    }
+
+    #[test]
+    fn test_rrf_fills_missing_snippet_and_merges_suggestions() {
+        let mut first = response(&["https://shared.example/"]);
+        first.suggestions = vec!["rust".to_string()];
+        let mut second = SearchResponse::from(vec![SearchResult {
+            snippet: "From second backend".to_string(),
+            ..result("https://shared.example/")
+        }]);
+        second.suggestions = vec!["rust".to_string(), "rust book".to_string()];
+
+        let fused = reciprocal_rank_fusion(
+            vec![("a".to_string(), first), ("b".to_string(), second)],
+            10,
+        );
+        assert_eq!(fused.results[0].snippet, "From second backend");
+        assert_eq!(fused.suggestions, vec!["rust", "rust book"]);
+    }
+}
@@ -0,0 +1,231 @@
+pub mod brave;
+pub mod duckduckgo;
+pub mod fusion;
+pub mod searxng;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::error::WebSearchError;
+use crate::models::search::{SafeSearch, SearchOptions, SearchResponse};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BackendCapabilities {
+    pub pagination: bool,
+    pub region: bool,
+    pub safe_search: bool,
+    pub time_range: bool,
+}
+
+impl BackendCapabilities {
+    /// Parameter names in `options` that a backend with these capabilities would ignore.
+    pub fn unsupported_options(&self, options: &SearchOptions) -> Vec<&'static str> {
+        let mut unsupported = Vec::new();
+        if !self.pagination && options.page > 1 {
+            unsupported.push("page");
+        }
+        if !self.region && options.region.is_some() {
+            unsupported.push("region");
+        }
+        if !self.safe_search && options.safe_search != SafeSearch::Moderate {
+            unsupported.push("safe_search");
+        }
+        if !self.time_range && options.time_range.is_some() {
+            unsupported.push("time_range");
+        }
+        unsupported
+    }
+}
+
+#[async_trait]
+pub trait SearchBackend: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn attribution(&self) -> &str {
+        self.name()
+    }
+
+    fn capabilities(&self) -> BackendCapabilities;
+
+    async fn search(
+        &self,
+        query: &str,
+        options: &SearchOptions,
+    ) -> Result<SearchResponse, WebSearchError>;
+}
+
+/// Runs `backend.search` and reports every option the backend cannot honour as a
+/// warning on the response instead of silently dropping it.
+pub async fn search_backend(
+    backend: &dyn SearchBackend,
+    query: &str,
+    options: &SearchOptions,
+) -> Result<SearchResponse, WebSearchError> {
+    let mut response = backend.search(query, options).await?;
+    for option in backend.capabilities().unsupported_options(options) {
+        response.warnings.push(format!(
+            "{} does not support {option}; it was ignored",
+            backend.name()
+        ));
+    }
+    Ok(response)
+}
+
+#[derive(Clone, Default)]
+pub struct BackendRegistry {
+    backends: Vec<Arc<dyn SearchBackend>>,
+}
+
+impl std::fmt::Debug for BackendRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BackendRegistry")
+            .field("backends", &self.names())
+            .finish()
+    }
+}
+
+impl BackendRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, backend: Arc<dyn SearchBackend>) {
+        self.backends.retain(|b| b.name() != backend.name());
+        self.backends.push(backend);
+    }
+
+    pub fn get(&self, name: &str) -> Option<Arc<dyn SearchBackend>> {
+        self.backends
+            .iter()
+            .find(|b| b.name().eq_ignore_ascii_case(name))
+            .cloned()
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.backends.iter().map(|b| b.name()).collect()
+    }
+
+    pub fn all(&self) -> Vec<Arc<dyn SearchBackend>> {
+        self.backends.clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.backends.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.backends.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::search::{SearchResult, TimeRange};
+
+    struct StaticBackend {
+        name: &'static str,
+        results: Vec<SearchResult>,
+    }
+
+    #[async_trait]
+    impl SearchBackend for StaticBackend {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn capabilities(&self) -> BackendCapabilities {
+            BackendCapabilities::default()
+        }
+
+        async fn search(
+            &self,
+            _query: &str,
+            options: &SearchOptions,
+        ) -> Result<SearchResponse, WebSearchError> {
+            Ok(self
+                .results
+                .iter()
+                .take(options.max_results)
+                .cloned()
+                .collect::<Vec<_>>()
+                .into())
+        }
+    }
+
+    fn static_backend(name: &'static str, titles: &[&str]) -> Arc<dyn SearchBackend> {
+        Arc::new(StaticBackend {
+            name,
+            results: titles
+                .iter()
+                .map(|title| SearchResult {
+                    title: title.to_string(),
+                    url: format!("https://example.com/{title}"),
+                    ..Default::default()
+                })
+                .collect(),
+        })
+    }
+
+    #[test]
+    fn test_registry_get_by_name() {
+        let mut registry = BackendRegistry::new();
+        registry.register(static_backend("first", &["a"]));
+        registry.register(static_backend("second", &["b"]));
+        assert_eq!(registry.get("second").unwrap().name(), "second");
+        assert_eq!(registry.get("SECOND").unwrap().name(), "second");
+        assert!(registry.get("missing").is_none());
+    }
+
+    #[test]
+    fn test_registry_register_replaces_same_name() {
+        let mut registry = BackendRegistry::new();
+        registry.register(static_backend("first", &["a"]));
+        registry.register(static_backend("first", &["b"]));
+        assert_eq!(registry.names(), vec!["first"]);
+    }
+
+    #[test]
+    fn test_attribution_defaults_to_name() {
+        let backend = static_backend("static", &[]);
+        assert_eq!(backend.attribution(), "static");
+    }
+
+    #[tokio::test]
+    async fn test_search_backend_warns_about_unsupported_options() {
+        let backend = static_backend("static", &["a"]);
+        let options = SearchOptions {
+            page: 2,
+            time_range: Some(TimeRange::Week),
+            ..Default::default()
+        };
+        let response = search_backend(backend.as_ref(), "query", &options)
+            .await
+            .unwrap();
+        assert_eq!(
+            response.warnings,
+            vec![
+                "static does not support page; it was ignored",
+                "static does not support time_range; it was ignored",
+            ]
+        );
+
+        let response = search_backend(backend.as_ref(), "query", &SearchOptions::default())
+            .await
+            .unwrap();
+        assert!(response.warnings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_through_trait_object() {
+        let backend = static_backend("static", &["a", "b", "c"]);
+        let options = SearchOptions {
+            max_results: 2,
+            ..Default::default()
+        };
+        let response = backend.search("query", &options).await.unwrap();
+        assert_eq!(response.results.len(), 2);
+        assert_eq!(response.results[0].title, "a");
+    }
+}
@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::backends::{BackendCapabilities, SearchBackend};
+use crate::error::WebSearchError;
+use crate::http_client::fetch_text;
+use crate::models::search::{
+    region_locale, SafeSearch, SearchOptions, SearchResponse, SearchResult,
+};
+use crate::models::searxng::SearxngResponse;
+use crate::rate_limiter::RateLimiter;
+
+const MAX_PAGES: usize = 5;
+
+#[derive(Debug, Clone)]
+pub struct SearxngBackend {
+    client: reqwest::Client,
+    base_url: String,
+    rate_limiter: Arc<RateLimiter>,
+    timeout_secs: u64,
+}
+
+impl SearxngBackend {
+    pub fn new(
+        client: reqwest::Client,
+        base_url: String,
+        rate_limiter: Arc<RateLimiter>,
+        timeout_secs: u64,
+    ) -> Self {
+        Self {
+            client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            rate_limiter,
+            timeout_secs,
+        }
+    }
+
+    async fn fetch_page(
+        &self,
+        query: &str,
+        options: &SearchOptions,
+        pageno: usize,
+    ) -> Result<SearxngResponse, WebSearchError> {
+        let mut params = vec![
+            ("q", query.to_string()),
+            ("format", "json".to_string()),
+            ("pageno", pageno.to_string()),
+            (
+                "safesearch",
+                safesearch_value(options.safe_search).to_string(),
+            ),
+        ];
+        if let Some(language) = options.region.as_deref().and_then(searxng_language) {
+            params.push(("language", language));
+        }
+        if let Some(time_range) = options.time_range {
+            params.push(("time_range", time_range.as_str().to_string()));
+        }
+
+        self.rate_limiter.acquire().await;
+        let request = self
+            .client
+            .get(format!("{}/search", self.base_url))
+            .query(&params);
+        let body = fetch_text(request, self.timeout_secs).await?;
+        Ok(serde_json::from_str(&body)?)
+    }
+}
+
+fn safesearch_value(safe_search: SafeSearch) -> u8 {
+    match safe_search {
+        SafeSearch::Off => 0,
+        SafeSearch::Moderate => 1,
+        SafeSearch::Strict => 2,
+    }
+}
+
+pub fn searxng_language(region: &str) -> Option<String> {
+    let (language, country) = region_locale(region)?;
+    Some(format!("{language}-{country}"))
+}
+
+#[async_trait]
+impl SearchBackend for SearxngBackend {
+    fn name(&self) -> &str {
+        "searxng"
+    }
+
+    fn attribution(&self) -> &str {
+        "SearXNG"
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            pagination: true,
+            region: true,
+            safe_search: true,
+            time_range: true,
+        }
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        options: &SearchOptions,
+    ) -> Result<SearchResponse, WebSearchError> {
+        if query.is_empty() {
+            return Err(WebSearchError::EmptyQuery);
+        }
+
+        let query = options.query_with_site_operators(query);
+        let first_page = options.page.max(1);
+        let mut response = SearchResponse::default();
+
+        for pageno in first_page..first_page + MAX_PAGES {
+            if response.results.len() >= options.max_results {
+                break;
+            }
+            let page = self.fetch_page(&query, options, pageno).await?;
+            if pageno == first_page {
+                response.infoboxes = page.infoboxes.into_iter().map(Into::into).collect();
+                response.suggestions = page.suggestions;
+            }
+            if page.results.is_empty() {
+                break;
+            }
+            let remaining = options.max_results - response.results.len();
+            response.results.extend(
+                page.results
+                    .into_iter()
+                    .map(SearchResult::from)
+                    .filter(|result| options.allows_url(&result.url))
+                    .take(remaining),
+            );
+        }
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_searxng_language_from_ddg_region() {
+        assert_eq!(searxng_language("us-en").as_deref(), Some("en-US"));
+        assert_eq!(searxng_language("jp-jp").as_deref(), Some("ja-JP"));
+        assert_eq!(searxng_language("uk-en").as_deref(), Some("en-GB"));
+        assert_eq!(searxng_language("wt-wt"), None);
+        assert_eq!(searxng_language("invalid"), None);
+    }
+
+    #[test]
+    fn test_safesearch_values() {
+        assert_eq!(safesearch_value(SafeSearch::Off), 0);
+        assert_eq!(safesearch_value(SafeSearch::Moderate), 1);
+        assert_eq!(safesearch_value(SafeSearch::Strict), 2);
+    }
+}
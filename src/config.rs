@@ -1,11 +1,21 @@
+use crate::models::search::SafeSearch;
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub max_results: usize,
     pub timeout_secs: u64,
     pub user_agent: String,
     pub cache_ttl_secs: u64,
+    pub suggestion_cache_ttl_secs: u64,
     pub rate_limit_ms: u64,
     pub max_retries: u32,
+    pub region: Option<String>,
+    pub safe_search: SafeSearch,
+    pub backend: String,
+    pub searxng_url: Option<String>,
+    pub brave_api_key: Option<String>,
+    pub brave_base_url: String,
+    pub brave_rate_limit_ms: u64,
 }
 
 impl Config {
@@ -25,6 +35,10 @@ impl Config {
                 .ok()
                 .and_then(|v| v.parse().ok())
                 .unwrap_or(300),
+            suggestion_cache_ttl_secs: std::env::var("WEBSEARCH_SUGGESTION_CACHE_TTL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(60),
             rate_limit_ms: std::env::var("WEBSEARCH_RATE_LIMIT_MS")
                 .ok()
                 .and_then(|v| v.parse().ok())
@@ -33,6 +47,36 @@ impl Config {
                 .ok()
                 .and_then(|v| v.parse().ok())
                 .unwrap_or(3),
+            region: std::env::var("WEBSEARCH_REGION")
+                .ok()
+                .map(|v| v.trim().to_ascii_lowercase())
+                .filter(|v| !v.is_empty()),
+            safe_search: std::env::var("WEBSEARCH_SAFE_SEARCH")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_default(),
+            backend: std::env::var("WEBSEARCH_BACKEND")
+                .ok()
+                .map(|v| v.trim().to_ascii_lowercase())
+                .filter(|v| !v.is_empty())
+                .unwrap_or_else(|| "duckduckgo".to_string()),
+            searxng_url: std::env::var("WEBSEARCH_SEARXNG_URL")
+                .ok()
+                .map(|v| v.trim().trim_end_matches('/').to_string())
+                .filter(|v| !v.is_empty()),
+            brave_api_key: std::env::var("WEBSEARCH_BRAVE_API_KEY")
+                .ok()
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty()),
+            brave_base_url: std::env::var("WEBSEARCH_BRAVE_BASE_URL")
+                .ok()
+                .map(|v| v.trim().trim_end_matches('/').to_string())
+                .filter(|v| !v.is_empty())
+                .unwrap_or_else(|| "https://api.search.brave.com".to_string()),
+            brave_rate_limit_ms: std::env::var("WEBSEARCH_BRAVE_RATE_LIMIT_MS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(1000),
         }
     }
 }
@@ -84,6 +128,21 @@ mod tests {
         assert_eq!(config.cache_ttl_secs, 300);
     }
 
+    #[test]
+    fn test_default_suggestion_cache_ttl_secs() {
+        std::env::remove_var("WEBSEARCH_SUGGESTION_CACHE_TTL_SECS");
+        let config = Config::from_env();
+        assert_eq!(config.suggestion_cache_ttl_secs, 60);
+    }
+
+    #[test]
+    fn test_custom_suggestion_cache_ttl_secs() {
+        std::env::set_var("WEBSEARCH_SUGGESTION_CACHE_TTL_SECS", "15");
+        let config = Config::from_env();
+        assert_eq!(config.suggestion_cache_ttl_secs, 15);
+        std::env::remove_var("WEBSEARCH_SUGGESTION_CACHE_TTL_SECS");
+    }
+
     #[test]
     fn test_default_rate_limit_ms() {
         std::env::remove_var("WEBSEARCH_RATE_LIMIT_MS");
@@ -145,4 +204,90 @@ mod tests {
         assert_eq!(config.max_retries, 3);
         std::env::remove_var("WEBSEARCH_MAX_RETRIES");
     }
+
+    #[test]
+    fn test_default_region_and_safe_search() {
+        std::env::remove_var("WEBSEARCH_REGION");
+        std::env::remove_var("WEBSEARCH_SAFE_SEARCH");
+        let config = Config::from_env();
+        assert_eq!(config.region, None);
+        assert_eq!(config.safe_search, SafeSearch::Moderate);
+    }
+
+    #[test]
+    fn test_custom_region_and_safe_search() {
+        std::env::set_var("WEBSEARCH_REGION", "JP-JP");
+        std::env::set_var("WEBSEARCH_SAFE_SEARCH", "strict");
+        let config = Config::from_env();
+        assert_eq!(config.region.as_deref(), Some("jp-jp"));
+        assert_eq!(config.safe_search, SafeSearch::Strict);
+        std::env::remove_var("WEBSEARCH_REGION");
+        std::env::remove_var("WEBSEARCH_SAFE_SEARCH");
+    }
+
+    #[test]
+    fn test_invalid_safe_search_uses_default() {
+        std::env::set_var("WEBSEARCH_SAFE_SEARCH", "abc");
+        let config = Config::from_env();
+        assert_eq!(config.safe_search, SafeSearch::Moderate);
+        std::env::remove_var("WEBSEARCH_SAFE_SEARCH");
+    }
+
+    #[test]
+    fn test_default_backend() {
+        std::env::remove_var("WEBSEARCH_BACKEND");
+        let config = Config::from_env();
+        assert_eq!(config.backend, "duckduckgo");
+    }
+
+    #[test]
+    fn test_custom_backend() {
+        std::env::set_var("WEBSEARCH_BACKEND", " SearXNG ");
+        let config = Config::from_env();
+        assert_eq!(config.backend, "searxng");
+        std::env::remove_var("WEBSEARCH_BACKEND");
+    }
+
+    #[test]
+    fn test_default_searxng_url() {
+        std::env::remove_var("WEBSEARCH_SEARXNG_URL");
+        let config = Config::from_env();
+        assert_eq!(config.searxng_url, None);
+    }
+
+    #[test]
+    fn test_custom_searxng_url_trims_trailing_slash() {
+        std::env::set_var("WEBSEARCH_SEARXNG_URL", "https://searx.example.com/");
+        let config = Config::from_env();
+        assert_eq!(
+            config.searxng_url.as_deref(),
+            Some("https://searx.example.com")
+        );
+        std::env::remove_var("WEBSEARCH_SEARXNG_URL");
+    }
+
+    #[test]
+    fn test_default_brave_settings() {
+        std::env::remove_var("WEBSEARCH_BRAVE_API_KEY");
+        std::env::remove_var("WEBSEARCH_BRAVE_BASE_URL");
+        std::env::remove_var("WEBSEARCH_BRAVE_RATE_LIMIT_MS");
+        let config = Config::from_env();
+        assert_eq!(config.brave_api_key, None);
+        assert_eq!(config.brave_base_url, "https://api.search.brave.com");
+        assert_eq!(config.brave_rate_limit_ms, 1000);
+    }
+
+    #[test]
+    fn test_custom_brave_settings() {
+        std::env::set_var("WEBSEARCH_BRAVE_API_KEY", "secret-token");
+        std::env::set_var("WEBSEARCH_BRAVE_BASE_URL", "http://localhost:8080/");
+        std::env::set_var("WEBSEARCH_BRAVE_RATE_LIMIT_MS", "50");
+        let config = Config::from_env();
+        assert_eq!(config.brave_api_key.as_deref(), Some("secret-token"));
+        assert_eq!(config.brave_base_url, "http://localhost:8080");
+        assert_eq!(config.brave_rate_limit_ms, 50);
+        std::env::remove_var("WEBSEARCH_BRAVE_API_KEY");
+        std::env::remove_var("WEBSEARCH_BRAVE_BASE_URL");
+        std::env::remove_var("WEBSEARCH_BRAVE_RATE_LIMIT_MS");
+    }
 }
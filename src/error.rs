@@ -11,10 +11,30 @@ pub enum WebSearchError {
     JsonParseError(#[from] serde_json::Error),
     #[error("Query must not be empty")]
     EmptyQuery,
+    #[error("Page {0} is outside the supported range 1-10")]
+    PageOutOfRange(usize),
     #[error("Request timed out after {0} seconds")]
     Timeout(u64),
     #[error("No results found for query: {0}")]
     NoResults(String),
+    #[error("Unknown search backend: {0}")]
+    UnknownBackend(String),
+    #[error("{0} rejected the API key")]
+    Unauthorized(String),
+    #[error("{0} requires payment or the plan quota is exhausted")]
+    PaymentRequired(String),
+    #[error("{0} rate limit exceeded")]
+    RateLimited(String),
+    #[error("{0} served a bot-detection page")]
+    Blocked(String),
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(String),
+    #[error("Unsupported content type: {0}")]
+    UnsupportedContentType(String),
+    #[error("Page returned HTTP {0}")]
+    PageUnavailable(u16),
+    #[error("Page exceeds the {0} byte limit")]
+    DocumentTooLarge(usize),
 }
 
 impl WebSearchError {
@@ -26,8 +46,30 @@ impl WebSearchError {
             }
             Self::JsonParseError(_) => "Failed to parse API response.",
             Self::EmptyQuery => "Query must not be empty.",
+            Self::PageOutOfRange(_) => "Page must be between 1 and 10.",
             Self::Timeout(_) => "Request timed out. Please try again.",
             Self::NoResults(_) => "No results found.",
+            Self::UnknownBackend(_) => "The configured search backend is not available.",
+            Self::Unauthorized(_) => {
+                "The search API rejected the API key. Check the configuration."
+            }
+            Self::PaymentRequired(_) => {
+                "The search API plan quota is exhausted or requires payment."
+            }
+            Self::RateLimited(_) => {
+                "The search API rate limit was exceeded. Please try again later."
+            }
+            Self::Blocked(_) => {
+                "The search engine is temporarily blocking automated requests (CAPTCHA). Please try again later or use another backend."
+            }
+            Self::InvalidUrl(_) => "The URL is invalid. Only http and https URLs can be fetched.",
+            Self::UnsupportedContentType(_) => {
+                "The page is not HTML or plain text and cannot be converted to Markdown."
+            }
+            Self::PageUnavailable(_) => {
+                "The page could not be retrieved. It may not exist or may block automated access."
+            }
+            Self::DocumentTooLarge(_) => "The page is too large to fetch.",
         }
     }
 
@@ -39,7 +81,8 @@ impl WebSearchError {
                         .map(|s| s == 429 || s.is_server_error())
                         .unwrap_or(false)
             }
-            Self::Timeout(_) => true,
+            Self::Timeout(_) | Self::RateLimited(_) | Self::Blocked(_) => true,
+            Self::PageUnavailable(status) => *status == 429 || *status >= 500,
             _ => false,
         }
     }
@@ -115,6 +158,60 @@ mod tests {
         assert!(!err.is_retryable());
     }
 
+    #[test]
+    fn test_unknown_backend_is_error_and_not_retryable() {
+        let err = WebSearchError::UnknownBackend("missing".to_string());
+        assert_eq!(
+            err.user_message(),
+            "The configured search backend is not available."
+        );
+        assert!(!err.is_retryable());
+        assert_eq!(err.to_tool_result().is_error, Some(true));
+    }
+
+    #[test]
+    fn test_page_out_of_range_names_allowed_range() {
+        let err = WebSearchError::PageOutOfRange(11);
+        assert_eq!(err.user_message(), "Page must be between 1 and 10.");
+        assert!(!err.is_retryable());
+        assert_eq!(err.to_tool_result().is_error, Some(true));
+    }
+
+    #[test]
+    fn test_api_key_errors_are_not_retryable() {
+        assert!(!WebSearchError::Unauthorized("Brave Search".to_string()).is_retryable());
+        assert!(!WebSearchError::PaymentRequired("Brave Search".to_string()).is_retryable());
+    }
+
+    #[test]
+    fn test_rate_limited_is_retryable() {
+        let err = WebSearchError::RateLimited("Brave Search".to_string());
+        assert!(err.is_retryable());
+        assert_eq!(err.to_tool_result().is_error, Some(true));
+    }
+
+    #[test]
+    fn test_blocked_is_retryable_error() {
+        let err = WebSearchError::Blocked("DuckDuckGo".to_string());
+        assert!(err.is_retryable());
+        assert!(err.user_message().contains("CAPTCHA"));
+        assert_eq!(err.to_tool_result().is_error, Some(true));
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let err = WebSearchError::InvalidUrl("ftp://example.com".to_string());
+        assert!(!err.is_retryable());
+        assert_eq!(err.to_tool_result().is_error, Some(true));
+        assert!(
+            !WebSearchError::UnsupportedContentType("application/pdf".to_string()).is_retryable()
+        );
+        assert!(!WebSearchError::PageUnavailable(404).is_retryable());
+        assert!(WebSearchError::PageUnavailable(503).is_retryable());
+        assert!(WebSearchError::PageUnavailable(429).is_retryable());
+        assert!(!WebSearchError::DocumentTooLarge(1024).is_retryable());
+    }
+
     #[test]
     fn test_is_retryable_no_results() {
         let err = WebSearchError::NoResults("test".to_string());
@@ -0,0 +1,308 @@
+use std::collections::HashMap;
+
+use scraper::{ElementRef, Html, Node, Selector};
+
+use crate::markdown::MAX_DEPTH;
+
+/// Paragraph-like elements whose text is credited to their ancestors. `div` and `td`
+/// only count when they hold text directly rather than wrapping other blocks.
+const PARAGRAPH_SELECTOR: &str = "p, pre, blockquote, td, div";
+
+const MIN_PARAGRAPH_CHARS: usize = 25;
+
+/// How many ancestors a paragraph's score is propagated to.
+const SCORED_ANCESTORS: usize = 3;
+
+const IGNORED_TAGS: &[&str] = &["script", "style", "noscript", "template", "svg"];
+
+const UNLIKELY_TAGS: &[&str] = &["nav", "footer", "aside"];
+
+const UNLIKELY_ROLES: &[&str] = &[
+    "navigation",
+    "complementary",
+    "contentinfo",
+    "banner",
+    "dialog",
+    "alertdialog",
+    "menu",
+    "menubar",
+];
+
+const BLOCK_CHILD_TAGS: &[&str] = &[
+    "p",
+    "div",
+    "section",
+    "article",
+    "main",
+    "pre",
+    "table",
+    "ul",
+    "ol",
+    "dl",
+    "blockquote",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "form",
+];
+
+const POSITIVE_HINTS: &[&str] = &[
+    "article", "body", "content", "entry", "hentry", "main", "page", "post", "text", "blog",
+    "story",
+];
+
+const NEGATIVE_HINTS: &[&str] = &[
+    "banner",
+    "breadcrumb",
+    "combx",
+    "comment",
+    "consent",
+    "cookie",
+    "disqus",
+    "footer",
+    "gdpr",
+    "masthead",
+    "menu",
+    "modal",
+    "navbar",
+    "newsletter",
+    "popup",
+    "promo",
+    "related",
+    "share",
+    "sidebar",
+    "social",
+    "sponsor",
+    "subscribe",
+    "toolbar",
+    "widget",
+];
+
+/// Picks the element most likely to hold the page's main content.
+///
+/// Every paragraph-like element scores by its length and comma count, and the
+/// score is credited to its nearest ancestors. Ancestors get a bonus for
+/// semantic tags (`<article>`, `<main>`) and content-like class names, and the
+/// final score is scaled down by the share of text that sits inside links.
+/// Returns `None` when no paragraph is long enough to judge.
+pub fn main_content(document: &Html) -> Option<ElementRef<'_>> {
+    let selector = Selector::parse(PARAGRAPH_SELECTOR).unwrap();
+    let mut candidates = HashMap::new();
+
+    for paragraph in document.select(&selector) {
+        if matches!(paragraph.value().name(), "div" | "td") && has_block_children(paragraph) {
+            continue;
+        }
+        if is_unlikely(paragraph)
+            || paragraph
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .any(is_unlikely)
+        {
+            continue;
+        }
+        let text = visible_text(paragraph);
+        let length = text.chars().count();
+        if length < MIN_PARAGRAPH_CHARS {
+            continue;
+        }
+        let commas = text
+            .chars()
+            .filter(|c| matches!(c, ',' | '，' | '、'))
+            .count();
+        let score = 1.0 + commas as f64 + (length as f64 / 100.0).min(3.0);
+
+        for (level, ancestor) in paragraph
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .take(SCORED_ANCESTORS)
+            .enumerate()
+        {
+            let divider = match level {
+                0 => 1.0,
+                1 => 2.0,
+                _ => level as f64 * 3.0,
+            };
+            candidates
+                .entry(ancestor.id())
+                .or_insert_with(|| (ancestor, initial_score(ancestor)))
+                .1 += score / divider;
+        }
+    }
+
+    candidates
+        .into_values()
+        .map(|(element, score)| (element, score * (1.0 - link_density(element))))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(element, _)| element)
+}
+
+fn initial_score(element: ElementRef) -> f64 {
+    let value = element.value();
+    let tag_score = match value.name() {
+        "article" | "main" => 25.0,
+        _ if matches!(value.attr("role"), Some("main" | "article")) => 25.0,
+        "div" => 5.0,
+        "pre" | "td" | "blockquote" => 3.0,
+        "ol" | "ul" | "dl" | "dd" | "dt" | "li" | "form" => -3.0,
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "th" => -5.0,
+        _ => 0.0,
+    };
+    tag_score + class_weight(element)
+}
+
+fn class_weight(element: ElementRef) -> f64 {
+    let hints = hint_text(element);
+    let mut weight = 0.0;
+    if NEGATIVE_HINTS.iter().any(|hint| hints.contains(hint)) {
+        weight -= 25.0;
+    }
+    if POSITIVE_HINTS.iter().any(|hint| hints.contains(hint)) {
+        weight += 25.0;
+    }
+    weight
+}
+
+fn hint_text(element: ElementRef) -> String {
+    let value = element.value();
+    format!(
+        "{} {}",
+        value.attr("class").unwrap_or_default(),
+        value.attr("id").unwrap_or_default()
+    )
+    .to_ascii_lowercase()
+}
+
+/// Navigation, footers, cookie banners and the like never hold the main content.
+fn is_unlikely(element: ElementRef) -> bool {
+    let value = element.value();
+    if matches!(value.name(), "body" | "html" | "article" | "main") {
+        return false;
+    }
+    if UNLIKELY_TAGS.contains(&value.name())
+        || value
+            .attr("role")
+            .is_some_and(|role| UNLIKELY_ROLES.contains(&role))
+    {
+        return true;
+    }
+    let hints = hint_text(element);
+    NEGATIVE_HINTS.iter().any(|hint| hints.contains(hint))
+        && !POSITIVE_HINTS.iter().any(|hint| hints.contains(hint))
+}
+
+fn has_block_children(element: ElementRef) -> bool {
+    element
+        .child_elements()
+        .any(|child| BLOCK_CHILD_TAGS.contains(&child.value().name()))
+}
+
+fn visible_text(element: ElementRef) -> String {
+    let mut text = String::new();
+    collect_text(element, &mut text, 0);
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn collect_text(element: ElementRef, output: &mut String, depth: usize) {
+    if depth > MAX_DEPTH {
+        return;
+    }
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => output.push_str(text),
+            Node::Element(child_element) if !IGNORED_TAGS.contains(&child_element.name()) => {
+                if let Some(child) = ElementRef::wrap(child) {
+                    collect_text(child, output, depth + 1);
+                }
+                output.push(' ');
+            }
+            _ => {}
+        }
+    }
+}
+
+fn link_density(element: ElementRef) -> f64 {
+    let total = visible_text(element).chars().count();
+    if total == 0 {
+        return 0.0;
+    }
+    let selector = Selector::parse("a").unwrap();
+    let linked: usize = element
+        .select(&selector)
+        .map(|link| visible_text(link).chars().count())
+        .sum();
+    (linked as f64 / total as f64).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAGRAPH: &str = "This paragraph is long enough, with commas, to count as real content.";
+
+    fn main_content_id(html: &str) -> Option<String> {
+        let document = Html::parse_document(html);
+        main_content(&document).and_then(|e| e.value().attr("id").map(str::to_string))
+    }
+
+    #[test]
+    fn test_prefers_article_over_sidebar() {
+        let html = format!(
+            "<body><div id='sidebar'><p>{PARAGRAPH}</p></div>\
+             <article id='story'><p>{PARAGRAPH}</p><p>{PARAGRAPH}</p></article></body>"
+        );
+        assert_eq!(main_content_id(&html).as_deref(), Some("story"));
+    }
+
+    #[test]
+    fn test_penalizes_link_heavy_blocks() {
+        let links = "<a href='/a'>A long link title that fills the line nicely</a>, ".repeat(5);
+        let html = format!(
+            "<body><div id='links'><p>{links}</p><p>{links}</p></div>\
+             <div id='text'><p>{PARAGRAPH}</p></div></body>"
+        );
+        assert_eq!(main_content_id(&html).as_deref(), Some("text"));
+    }
+
+    #[test]
+    fn test_skips_cookie_banners() {
+        let banner = "We use cookies, trackers, pixels, beacons, and more, to improve things.";
+        let html = format!(
+            "<body><div id='cookie-banner'><p>{banner}</p><p>{banner}</p><p>{banner}</p></div>\
+             <div id='post'><p>{PARAGRAPH}</p></div></body>"
+        );
+        assert_eq!(main_content_id(&html).as_deref(), Some("post"));
+    }
+
+    #[test]
+    fn test_short_pages_have_no_main_content() {
+        assert_eq!(
+            main_content_id("<body><p id='x'>Too short.</p></body>"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_deeply_nested_markup_does_not_overflow() {
+        let depth = MAX_DEPTH * 4;
+        let html = format!(
+            "<body><div id='post'><p>{PARAGRAPH}</p></div>{}{PARAGRAPH}{}</body>",
+            "<div>".repeat(depth),
+            "</div>".repeat(depth)
+        );
+        assert_eq!(main_content_id(&html).as_deref(), Some("post"));
+    }
+
+    #[test]
+    fn test_link_density() {
+        let document = Html::parse_fragment("<div><a href='/'>half</a> text</div>");
+        let div = document
+            .select(&Selector::parse("div").unwrap())
+            .next()
+            .unwrap();
+        assert!((link_density(div) - 4.0 / 9.0).abs() < f64::EPSILON);
+    }
+}
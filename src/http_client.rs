@@ -1,7 +1,8 @@
-use reqwest::Client;
+use reqwest::{Client, RequestBuilder, Response};
 use std::time::Duration;
 
 use crate::config::Config;
+use crate::error::WebSearchError;
 
 pub fn build_http_client(config: &Config) -> Result<Client, reqwest::Error> {
     Client::builder()
@@ -10,9 +11,28 @@ pub fn build_http_client(config: &Config) -> Result<Client, reqwest::Error> {
         .build()
 }
 
+pub async fn send(request: RequestBuilder, timeout_secs: u64) -> Result<Response, WebSearchError> {
+    request.send().await.map_err(|e| {
+        if e.is_timeout() {
+            WebSearchError::Timeout(timeout_secs)
+        } else {
+            WebSearchError::HttpError(e)
+        }
+    })
+}
+
+pub async fn fetch_text(
+    request: RequestBuilder,
+    timeout_secs: u64,
+) -> Result<String, WebSearchError> {
+    let response = send(request, timeout_secs).await?.error_for_status()?;
+    Ok(response.text().await?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::search::SafeSearch;
 
     #[test]
     fn test_build_http_client_succeeds() {
@@ -21,8 +41,16 @@ mod tests {
             timeout_secs: 10,
             user_agent: "websearch-mcp/0.1".to_string(),
             cache_ttl_secs: 300,
+            suggestion_cache_ttl_secs: 60,
             rate_limit_ms: 1000,
             max_retries: 3,
+            region: None,
+            safe_search: SafeSearch::Moderate,
+            backend: "duckduckgo".to_string(),
+            searxng_url: None,
+            brave_api_key: None,
+            brave_base_url: "https://api.search.brave.com".to_string(),
+            brave_rate_limit_ms: 1000,
         };
         assert!(build_http_client(&config).is_ok());
     }
@@ -34,8 +62,16 @@ mod tests {
             timeout_secs: 30,
             user_agent: "custom-agent/2.0".to_string(),
             cache_ttl_secs: 300,
+            suggestion_cache_ttl_secs: 60,
             rate_limit_ms: 1000,
             max_retries: 3,
+            region: None,
+            safe_search: SafeSearch::Moderate,
+            backend: "duckduckgo".to_string(),
+            searxng_url: None,
+            brave_api_key: None,
+            brave_base_url: "https://api.search.brave.com".to_string(),
+            brave_rate_limit_ms: 1000,
         };
         assert!(build_http_client(&config).is_ok());
     }
@@ -1,7 +1,10 @@
+pub mod backends;
 pub mod cache;
 pub mod config;
 pub mod error;
+pub mod extract;
 pub mod http_client;
+pub mod markdown;
 pub mod models;
 pub mod rate_limiter;
 pub mod retry;
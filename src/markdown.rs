@@ -0,0 +1,451 @@
+use reqwest::Url;
+use scraper::{ElementRef, Html, Node, Selector};
+
+/// Placeholder for list indentation so that `clean_up` can strip stray leading spaces.
+const INDENT: char = '\u{1}';
+
+/// Elements nested deeper than this are dropped so hostile pages cannot exhaust the stack.
+pub const MAX_DEPTH: usize = 512;
+
+const SKIPPED_TAGS: &[&str] = &[
+    "script", "style", "noscript", "template", "nav", "svg", "iframe", "button", "select", "head",
+];
+
+const BLOCK_TAGS: &[&str] = &[
+    "p",
+    "div",
+    "section",
+    "article",
+    "main",
+    "header",
+    "footer",
+    "aside",
+    "figure",
+    "figcaption",
+    "address",
+    "details",
+    "summary",
+    "dl",
+    "dt",
+    "dd",
+    "form",
+    "fieldset",
+    "body",
+    "html",
+];
+
+pub fn page_title(document: &Html) -> Option<String> {
+    let selector = Selector::parse("title").unwrap();
+    document
+        .select(&selector)
+        .next()
+        .map(|title| collapse_whitespace(&title.text().collect::<String>()))
+        .map(|title| title.trim().to_string())
+        .filter(|title| !title.is_empty())
+}
+
+pub fn html_to_markdown(html: &str, base_url: Option<&Url>) -> String {
+    let document = Html::parse_document(html);
+    element_to_markdown(document.root_element(), base_url)
+}
+
+pub fn element_to_markdown(element: ElementRef, base_url: Option<&Url>) -> String {
+    let converter = Converter { base_url };
+    clean_up(&converter.children(element, 0)).replace(INDENT, " ")
+}
+
+struct Converter<'a> {
+    base_url: Option<&'a Url>,
+}
+
+impl Converter<'_> {
+    fn children(&self, element: ElementRef, depth: usize) -> String {
+        let mut output = String::new();
+        for child in element.children() {
+            match child.value() {
+                Node::Text(text) => output.push_str(&collapse_whitespace(text)),
+                Node::Element(_) => {
+                    if let Some(child) = ElementRef::wrap(child) {
+                        output.push_str(&self.element(child, depth + 1));
+                    }
+                }
+                _ => {}
+            }
+        }
+        output
+    }
+
+    fn element(&self, element: ElementRef, depth: usize) -> String {
+        let tag = element.value().name();
+        if depth > MAX_DEPTH || SKIPPED_TAGS.contains(&tag) || is_hidden(element) {
+            return String::new();
+        }
+
+        match tag {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                let level = tag[1..].parse::<usize>().unwrap_or(1);
+                let text = self.children(element, depth);
+                let text = text.trim();
+                if text.is_empty() {
+                    String::new()
+                } else {
+                    format!("\n\n{} {}\n\n", "#".repeat(level), text)
+                }
+            }
+            "br" => "\n".to_string(),
+            "hr" => "\n\n---\n\n".to_string(),
+            "a" => self.link(element, depth),
+            "img" => self.image(element),
+            "strong" | "b" => wrap_inline(&self.children(element, depth), "**"),
+            "em" | "i" => wrap_inline(&self.children(element, depth), "_"),
+            "code" => {
+                let code = element.text().collect::<String>();
+                if code.trim().is_empty() {
+                    String::new()
+                } else {
+                    format!("`{}`", code.trim())
+                }
+            }
+            "pre" => code_block(element),
+            "blockquote" => {
+                let text = clean_up(&self.children(element, depth));
+                if text.is_empty() {
+                    return String::new();
+                }
+                let quoted: Vec<String> = text
+                    .lines()
+                    .map(|line| {
+                        if line.is_empty() {
+                            ">".to_string()
+                        } else {
+                            format!("> {line}")
+                        }
+                    })
+                    .collect();
+                format!("\n\n{}\n\n", quoted.join("\n"))
+            }
+            "ul" | "ol" => self.list(element, tag == "ol", depth),
+            "table" => self.table(element, depth),
+            "li" => format!("\n\n{}\n\n", self.children(element, depth).trim()),
+            _ if BLOCK_TAGS.contains(&tag) => {
+                let text = self.children(element, depth);
+                let text = text.trim();
+                if text.is_empty() {
+                    String::new()
+                } else {
+                    format!("\n\n{text}\n\n")
+                }
+            }
+            _ => self.children(element, depth),
+        }
+    }
+
+    fn link(&self, element: ElementRef, depth: usize) -> String {
+        let text = self.children(element, depth);
+        let text = text.trim();
+        if text.is_empty() {
+            return String::new();
+        }
+        let href = element.value().attr("href").unwrap_or_default().trim();
+        if href.is_empty() || href.starts_with('#') || href.starts_with("javascript:") {
+            return text.to_string();
+        }
+        format!("[{}]({})", text, self.resolve(href))
+    }
+
+    fn image(&self, element: ElementRef) -> String {
+        let Some(src) = element.value().attr("src").filter(|src| !src.is_empty()) else {
+            return String::new();
+        };
+        if src.starts_with("data:") {
+            return String::new();
+        }
+        let alt = collapse_whitespace(element.value().attr("alt").unwrap_or_default());
+        format!("![{}]({})", alt.trim(), self.resolve(src))
+    }
+
+    fn list(&self, element: ElementRef, ordered: bool, depth: usize) -> String {
+        let mut items = Vec::new();
+        for item in element.child_elements() {
+            if item.value().name() != "li" || is_hidden(item) {
+                continue;
+            }
+            let marker = if ordered {
+                format!("{}. ", items.len() + 1)
+            } else {
+                "- ".to_string()
+            };
+            let body = clean_up(&self.children(item, depth + 1));
+            let indent = INDENT.to_string().repeat(marker.len());
+            let mut lines = body.lines();
+            let mut rendered = format!("{}{}", marker, lines.next().unwrap_or_default());
+            for line in lines {
+                rendered.push('\n');
+                if !line.is_empty() {
+                    rendered.push_str(&indent);
+                    rendered.push_str(line);
+                }
+            }
+            items.push(rendered);
+        }
+        if items.is_empty() {
+            return String::new();
+        }
+        format!("\n\n{}\n\n", items.join("\n"))
+    }
+
+    fn table(&self, element: ElementRef, depth: usize) -> String {
+        // Rows of nested tables are rendered inside their own cell, not as rows of this table.
+        let rows: Vec<Vec<String>> = element
+            .child_elements()
+            .flat_map(|child| match child.value().name() {
+                "thead" | "tbody" | "tfoot" => child.child_elements().collect::<Vec<_>>(),
+                _ => vec![child],
+            })
+            .filter(|row| row.value().name() == "tr")
+            .map(|row| {
+                row.child_elements()
+                    .filter(|cell| matches!(cell.value().name(), "th" | "td"))
+                    .map(|cell| {
+                        clean_up(&self.children(cell, depth + 1))
+                            .replace('|', "\\|")
+                            .replace('\n', " ")
+                    })
+                    .collect::<Vec<String>>()
+            })
+            .filter(|cells| !cells.is_empty())
+            .collect();
+        let Some(columns) = rows.iter().map(Vec::len).max() else {
+            return String::new();
+        };
+
+        let mut output = String::from("\n\n");
+        for (i, row) in rows.iter().enumerate() {
+            let mut cells = row.clone();
+            cells.resize(columns, String::new());
+            output.push_str(&format!("| {} |\n", cells.join(" | ")));
+            if i == 0 {
+                output.push_str(&format!("|{}\n", "---|".repeat(columns)));
+            }
+        }
+        output.push('\n');
+        output
+    }
+
+    fn resolve(&self, href: &str) -> String {
+        self.base_url
+            .and_then(|base| base.join(href).ok())
+            .map(|url| url.to_string())
+            .unwrap_or_else(|| href.to_string())
+    }
+}
+
+fn is_hidden(element: ElementRef) -> bool {
+    let value = element.value();
+    value.attr("hidden").is_some() || value.attr("aria-hidden") == Some("true")
+}
+
+fn code_block(element: ElementRef) -> String {
+    let language = std::iter::once(element)
+        .chain(
+            element
+                .child_elements()
+                .filter(|c| c.value().name() == "code"),
+        )
+        .flat_map(|e| e.value().classes().collect::<Vec<_>>())
+        .find_map(|class| {
+            class
+                .strip_prefix("language-")
+                .or_else(|| class.strip_prefix("lang-"))
+                .map(str::to_string)
+        })
+        .unwrap_or_default();
+    let code = element.text().collect::<String>();
+    let code = code.trim_matches('\n').trim_end();
+    if code.is_empty() {
+        return String::new();
+    }
+    format!("\n\n```{language}\n{code}\n```\n\n")
+}
+
+fn wrap_inline(text: &str, marker: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return text.to_string();
+    }
+    let leading = if text.starts_with(char::is_whitespace) {
+        " "
+    } else {
+        ""
+    };
+    let trailing = if text.ends_with(char::is_whitespace) {
+        " "
+    } else {
+        ""
+    };
+    format!("{leading}{marker}{trimmed}{marker}{trailing}")
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut last_was_space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !last_was_space {
+                output.push(' ');
+            }
+            last_was_space = true;
+        } else {
+            output.push(c);
+            last_was_space = false;
+        }
+    }
+    output
+}
+
+/// Trims trailing spaces and collapses runs of blank lines, leaving fenced code untouched.
+fn clean_up(markdown: &str) -> String {
+    let mut output = String::with_capacity(markdown.len());
+    let mut in_fence = false;
+    let mut blank_lines = 0;
+    for line in markdown.lines() {
+        if line.trim_start_matches([' ', INDENT]).starts_with("```") {
+            in_fence = !in_fence;
+        }
+        let line = if in_fence {
+            line.trim_end()
+        } else {
+            line.trim_end().trim_start_matches(' ')
+        };
+        if line.is_empty() && !in_fence {
+            blank_lines += 1;
+            continue;
+        }
+        if !output.is_empty() && blank_lines > 0 {
+            output.push('\n');
+        }
+        blank_lines = 0;
+        output.push_str(line);
+        output.push('\n');
+    }
+    output.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(html: &str) -> String {
+        let base = Url::parse("https://example.com/docs/page").unwrap();
+        html_to_markdown(html, Some(&base))
+    }
+
+    #[test]
+    fn test_headings_and_paragraphs() {
+        let markdown =
+            convert("<h1>Title</h1><p>First   paragraph\n text.</p><h3>Sub</h3><p>Second</p>");
+        assert_eq!(
+            markdown,
+            "# Title\n\nFirst paragraph text.\n\n### Sub\n\nSecond"
+        );
+    }
+
+    #[test]
+    fn test_strips_scripts_styles_and_nav() {
+        let markdown = convert(
+            "<nav><a href='/'>Home</a></nav><script>alert(1)</script><style>p{}</style><p>Body</p>",
+        );
+        assert_eq!(markdown, "Body");
+    }
+
+    #[test]
+    fn test_links_are_resolved_against_base_url() {
+        let markdown =
+            convert("<p>See <a href='../guide'>the guide</a> and <a href='#top'>top</a>.</p>");
+        assert_eq!(
+            markdown,
+            "See [the guide](https://example.com/guide) and top."
+        );
+    }
+
+    #[test]
+    fn test_inline_formatting() {
+        let markdown =
+            convert("<p>Use <strong>cargo</strong> <em>now</em> via <code>cargo run</code>.</p>");
+        assert_eq!(markdown, "Use **cargo** _now_ via `cargo run`.");
+    }
+
+    #[test]
+    fn test_nested_lists() {
+        let markdown =
+            convert("<ul><li>One</li><li>Two<ol><li>Alpha</li><li>Beta</li></ol></li></ul>");
+        assert_eq!(markdown, "- One\n- Two\n\n  1. Alpha\n  2. Beta");
+    }
+
+    #[test]
+    fn test_code_block_keeps_whitespace_and_language() {
+        let markdown = convert(
+            "<pre><code class=\"language-rust\">fn main() {\n    println!(\"hi\");\n}\n</code></pre>",
+        );
+        assert_eq!(
+            markdown,
+            "```rust\nfn main() {\n    println!(\"hi\");\n}\n```"
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        let markdown = convert(
+            "<table><thead><tr><th>Name</th><th>Value</th></tr></thead>\
+             <tbody><tr><td>a|b</td><td>1</td></tr><tr><td>c</td></tr></tbody></table>",
+        );
+        assert_eq!(
+            markdown,
+            "| Name | Value |\n|---|---|\n| a\\|b | 1 |\n| c |  |"
+        );
+    }
+
+    #[test]
+    fn test_nested_table_rows_are_not_repeated() {
+        let markdown = convert(
+            "<table><tr><th>Outer</th></tr>\
+             <tr><td><table><tr><td>inner</td></tr></table></td></tr></table>",
+        );
+        assert_eq!(markdown.matches("inner").count(), 1);
+        assert!(markdown.starts_with("| Outer |\n|---|\n| "));
+    }
+
+    #[test]
+    fn test_blockquote_and_images() {
+        let markdown = convert(
+            "<blockquote><p>Quoted</p><p>Text</p></blockquote><img src='/logo.png' alt='Logo'>",
+        );
+        assert_eq!(
+            markdown,
+            "> Quoted\n>\n> Text\n\n![Logo](https://example.com/logo.png)"
+        );
+    }
+
+    #[test]
+    fn test_hidden_elements_are_skipped() {
+        let markdown = convert("<div hidden>secret</div><p aria-hidden='true'>x</p><p>shown</p>");
+        assert_eq!(markdown, "shown");
+    }
+
+    #[test]
+    fn test_deeply_nested_markup_is_cut_off() {
+        let depth = MAX_DEPTH * 4;
+        let html = format!(
+            "<p>top</p>{}deep{}",
+            "<div>".repeat(depth),
+            "</div>".repeat(depth)
+        );
+        assert_eq!(convert(&html), "top");
+    }
+
+    #[test]
+    fn test_page_title() {
+        let document = Html::parse_document("<title>  Rust\n Docs </title><p>x</p>");
+        assert_eq!(page_title(&document).as_deref(), Some("Rust Docs"));
+    }
+}
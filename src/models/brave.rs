@@ -0,0 +1,91 @@
+use serde::Deserialize;
+
+use crate::models::search::{registrable_domain, SearchResult};
+use crate::models::text::strip_html;
+
+#[derive(Debug, Deserialize)]
+pub struct BraveResponse {
+    #[serde(default)]
+    pub web: Option<BraveWebResults>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BraveWebResults {
+    #[serde(default)]
+    pub results: Vec<BraveResult>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BraveResult {
+    pub title: String,
+    pub url: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub age: Option<String>,
+    #[serde(default)]
+    pub page_age: Option<String>,
+    #[serde(default)]
+    pub extra_snippets: Vec<String>,
+}
+
+impl From<BraveResult> for SearchResult {
+    fn from(result: BraveResult) -> Self {
+        Self {
+            title: strip_html(&result.title),
+            domain: registrable_domain(&result.url),
+            url: result.url,
+            snippet: strip_html(&result.description),
+            date: result.age.or(result.page_age),
+            extra_snippets: result
+                .extra_snippets
+                .iter()
+                .map(|snippet| strip_html(snippet))
+                .filter(|snippet| !snippet.is_empty())
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_fixture() {
+        let json = include_str!("../../tests/fixtures/brave_results.json");
+        let resp: BraveResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.web.unwrap().results.len(), 3);
+    }
+
+    #[test]
+    fn test_deserialize_without_web_section() {
+        let resp: BraveResponse = serde_json::from_str(r#"{"type": "search"}"#).unwrap();
+        assert!(resp.web.is_none());
+    }
+
+    #[test]
+    fn test_result_prefers_age_over_page_age() {
+        let result: BraveResult = serde_json::from_str(
+            r#"{"title": "T", "url": "https://example.com", "age": "2 days ago", "page_age": "2026-10-16T00:00:00"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            SearchResult::from(result).date.as_deref(),
+            Some("2 days ago")
+        );
+    }
+
+    #[test]
+    fn test_result_falls_back_to_page_age() {
+        let result: BraveResult = serde_json::from_str(
+            r#"{"title": "T", "url": "https://example.com", "page_age": "2026-10-16T00:00:00"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            SearchResult::from(result).date.as_deref(),
+            Some("2026-10-16T00:00:00")
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use rmcp::schemars;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct ImageResponse {
+    #[serde(default)]
+    pub results: Vec<ImageResult>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ImageResult {
+    #[serde(default)]
+    pub title: String,
+    pub image: String,
+    #[serde(default)]
+    pub thumbnail: String,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub width: u32,
+    #[serde(default)]
+    pub height: u32,
+    #[serde(default)]
+    pub source: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageSize {
+    Small,
+    Medium,
+    Large,
+    Wallpaper,
+}
+
+impl ImageSize {
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            Self::Small => "Small",
+            Self::Medium => "Medium",
+            Self::Large => "Large",
+            Self::Wallpaper => "Wallpaper",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageColor {
+    Color,
+    Monochrome,
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+    Pink,
+    Brown,
+    Black,
+    Gray,
+    Teal,
+    White,
+}
+
+impl ImageColor {
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            Self::Color => "color",
+            Self::Monochrome => "Monochrome",
+            Self::Red => "Red",
+            Self::Orange => "Orange",
+            Self::Yellow => "Yellow",
+            Self::Green => "Green",
+            Self::Blue => "Blue",
+            Self::Purple => "Purple",
+            Self::Pink => "Pink",
+            Self::Brown => "Brown",
+            Self::Black => "Black",
+            Self::Gray => "Gray",
+            Self::Teal => "Teal",
+            Self::White => "White",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageType {
+    Photo,
+    Clipart,
+    Gif,
+    Transparent,
+    Line,
+}
+
+impl ImageType {
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            Self::Photo => "photo",
+            Self::Clipart => "clipart",
+            Self::Gif => "gif",
+            Self::Transparent => "transparent",
+            Self::Line => "line",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageLayout {
+    Square,
+    Tall,
+    Wide,
+}
+
+impl ImageLayout {
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            Self::Square => "Square",
+            Self::Tall => "Tall",
+            Self::Wide => "Wide",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImageFilters {
+    pub size: Option<ImageSize>,
+    pub color: Option<ImageColor>,
+    pub image_type: Option<ImageType>,
+    pub layout: Option<ImageLayout>,
+}
+
+impl ImageFilters {
+    pub fn f_param(&self) -> String {
+        format!(
+            "size:{},color:{},type:{},layout:{}",
+            self.size.map(|s| s.as_param()).unwrap_or_default(),
+            self.color.map(|c| c.as_param()).unwrap_or_default(),
+            self.image_type.map(|t| t.as_param()).unwrap_or_default(),
+            self.layout.map(|l| l.as_param()).unwrap_or_default(),
+        )
+    }
+}
+
+pub fn format_images_markdown(query: &str, results: &[ImageResult]) -> String {
+    let mut output = format!("## Image Results for \"{query}\"\n\n");
+
+    if results.is_empty() {
+        output.push_str("No images found.\n\n");
+        output.push_str("_Source: DuckDuckGo Images_");
+        return output;
+    }
+
+    for (i, result) in results.iter().enumerate() {
+        output.push_str(&format!("### {}. {}\n", i + 1, result.title));
+        if result.width > 0 && result.height > 0 {
+            output.push_str(&format!(
+                "**Image:** {} ({}x{})\n",
+                result.image, result.width, result.height
+            ));
+        } else {
+            output.push_str(&format!("**Image:** {}\n", result.image));
+        }
+        if !result.thumbnail.is_empty() {
+            output.push_str(&format!("**Thumbnail:** {}\n", result.thumbnail));
+        }
+        if !result.url.is_empty() {
+            output.push_str(&format!("**Page:** {}\n", result.url));
+        }
+        if !result.source.is_empty() {
+            output.push_str(&format!("**Source:** {}\n", result.source));
+        }
+        output.push_str("\n---\n\n");
+    }
+
+    output.push_str(&format!(
+        "_Source: DuckDuckGo Images ({} results)_",
+        results.len()
+    ));
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> ImageResponse {
+        serde_json::from_str(include_str!("../../tests/fixtures/image_results.json")).unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_image_fixture() {
+        let response = fixture();
+        assert_eq!(response.results.len(), 3);
+        assert_eq!(response.results[0].width, 1200);
+        assert_eq!(response.results[0].height, 1200);
+        assert_eq!(response.results[0].url, "https://www.rust-lang.org/");
+    }
+
+    #[test]
+    fn test_f_param_defaults_to_empty_filters() {
+        assert_eq!(
+            ImageFilters::default().f_param(),
+            "size:,color:,type:,layout:"
+        );
+    }
+
+    #[test]
+    fn test_f_param_maps_filters() {
+        let filters = ImageFilters {
+            size: Some(ImageSize::Large),
+            color: Some(ImageColor::Monochrome),
+            image_type: Some(ImageType::Transparent),
+            layout: Some(ImageLayout::Wide),
+        };
+        assert_eq!(
+            filters.f_param(),
+            "size:Large,color:Monochrome,type:transparent,layout:Wide"
+        );
+    }
+
+    #[test]
+    fn test_format_images_markdown() {
+        let output = format_images_markdown("rust logo", &fixture().results);
+        assert!(output.contains("### 1. Rust Logo - The Rust Programming Language\n"));
+        assert!(output.contains(
+            "**Image:** https://www.rust-lang.org/logos/rust-logo-512x512.png (1200x1200)\n"
+        ));
+        assert!(output.contains("**Thumbnail:** https://tse1.mm.bing.net/th?id=OIP.rust1\n"));
+        assert!(output.contains("**Page:** https://www.rust-lang.org/\n"));
+        assert!(output.contains("**Source:** Bing\n"));
+        assert!(output.ends_with("_Source: DuckDuckGo Images (3 results)_"));
+    }
+
+    #[test]
+    fn test_format_images_markdown_empty() {
+        let output = format_images_markdown("nothing", &[]);
+        assert!(output.contains("No images found."));
+    }
+}
@@ -1,6 +1,9 @@
-use serde::Deserialize;
+use rmcp::schemars;
+use serde::{Deserialize, Deserializer};
 
-#[derive(Debug, Deserialize)]
+const DUCKDUCKGO_ORIGIN: &str = "https://duckduckgo.com";
+
+#[derive(Debug, Default, Deserialize)]
 pub struct InstantAnswerResponse {
     #[serde(rename = "Abstract")]
     pub abstract_text: String,
@@ -10,14 +13,27 @@ pub struct InstantAnswerResponse {
     pub abstract_url: String,
     #[serde(rename = "Answer")]
     pub answer: String,
+    #[serde(rename = "AnswerType", default)]
+    pub answer_type: String,
     #[serde(rename = "Definition")]
     pub definition: String,
     #[serde(rename = "DefinitionSource")]
     pub definition_source: String,
     #[serde(rename = "DefinitionURL")]
     pub definition_url: String,
+    #[serde(rename = "Heading", default)]
+    pub heading: String,
+    #[serde(rename = "Image", default)]
+    pub image: String,
+    #[serde(rename = "Entity", default)]
+    pub entity: String,
+    #[serde(rename = "Infobox", default, deserialize_with = "deserialize_infobox")]
+    pub infobox: Option<InstantAnswerInfobox>,
     #[serde(rename = "RelatedTopics")]
     pub related_topics: Vec<RelatedTopic>,
+    /// Official site links
+    #[serde(rename = "Results", default)]
+    pub results: Vec<ResultItem>,
     #[serde(rename = "Type")]
     pub response_type: String,
 }
@@ -34,6 +50,100 @@ pub enum RelatedTopic {
     },
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Disambiguation {
+    /// Let DuckDuckGo answer with its best guess for an ambiguous query
+    #[default]
+    Skip,
+    /// Return the candidate meanings so the caller can pick one
+    List,
+}
+
+impl Disambiguation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Skip => "skip",
+            Self::List => "list",
+        }
+    }
+
+    pub fn skip_disambig_value(&self) -> &'static str {
+        match self {
+            Self::Skip => "1",
+            Self::List => "0",
+        }
+    }
+}
+
+impl InstantAnswerResponse {
+    pub fn response_type_label(&self) -> Option<&'static str> {
+        match self.response_type.as_str() {
+            "A" => Some("article"),
+            "D" => Some("disambiguation"),
+            "C" => Some("category"),
+            "N" => Some("name"),
+            "E" => Some("exclusive"),
+            _ => None,
+        }
+    }
+
+    /// `Image` is usually a path such as `/i/abc.png` relative to duckduckgo.com.
+    pub fn image_url(&self) -> Option<String> {
+        if self.image.is_empty() {
+            None
+        } else if self.image.starts_with('/') {
+            Some(format!("{}{}", DUCKDUCKGO_ORIGIN, self.image))
+        } else {
+            Some(self.image.clone())
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct InstantAnswerInfobox {
+    #[serde(default)]
+    pub content: Vec<InfoboxEntry>,
+    #[serde(default)]
+    pub meta: Vec<InfoboxEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InfoboxEntry {
+    #[serde(default)]
+    pub data_type: String,
+    #[serde(default)]
+    pub label: String,
+    #[serde(default)]
+    pub value: serde_json::Value,
+}
+
+impl InfoboxEntry {
+    /// Scalar values as text; structured values (e.g. Wikidata references) yield `None`.
+    pub fn display_value(&self) -> Option<String> {
+        match &self.value {
+            serde_json::Value::String(value) if !value.is_empty() => Some(value.clone()),
+            serde_json::Value::Number(value) => Some(value.to_string()),
+            serde_json::Value::Bool(value) => Some(value.to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// The API sends `"Infobox": ""` when there is no infobox.
+fn deserialize_infobox<'de, D>(deserializer: D) -> Result<Option<InstantAnswerInfobox>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+    if !value.is_object() {
+        return Ok(None);
+    }
+    let infobox: InstantAnswerInfobox =
+        serde_json::from_value(value).map_err(serde::de::Error::custom)?;
+    Ok(Some(infobox).filter(|infobox| !infobox.content.is_empty()))
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ResultItem {
     #[serde(rename = "Text")]
@@ -107,4 +217,86 @@ mod tests {
         let resp: InstantAnswerResponse = serde_json::from_str(json).unwrap();
         assert_eq!(resp.related_topics.len(), 2);
     }
+
+    #[test]
+    fn test_deserialize_heading_image_entity_and_results() {
+        let resp: InstantAnswerResponse =
+            serde_json::from_str(include_str!("../../tests/fixtures/instant_answer.json")).unwrap();
+        assert_eq!(resp.heading, "Rust (programming language)");
+        assert_eq!(resp.entity, "programming language");
+        assert_eq!(
+            resp.image_url().as_deref(),
+            Some("https://duckduckgo.com/i/a0a4d2b4.png")
+        );
+        assert_eq!(resp.results.len(), 1);
+        assert_eq!(resp.results[0].text, "Official site");
+        assert_eq!(resp.results[0].first_url, "https://www.rust-lang.org/");
+    }
+
+    #[test]
+    fn test_deserialize_infobox() {
+        let resp: InstantAnswerResponse = serde_json::from_str(include_str!(
+            "../../tests/fixtures/instant_answer_infobox.json"
+        ))
+        .unwrap();
+        let infobox = resp.infobox.unwrap();
+        assert_eq!(infobox.content.len(), 9);
+        assert_eq!(infobox.content[1].label, "Designed by");
+        assert_eq!(
+            infobox.content[1].display_value().as_deref(),
+            Some("Graydon Hoare")
+        );
+        assert_eq!(infobox.content[8].display_value(), None);
+        assert_eq!(infobox.meta[1].label, "template_name");
+    }
+
+    #[test]
+    fn test_deserialize_empty_string_infobox_as_none() {
+        let json = r#"{
+            "Abstract": "",
+            "AbstractSource": "",
+            "AbstractURL": "",
+            "Answer": "",
+            "Definition": "",
+            "DefinitionSource": "",
+            "DefinitionURL": "",
+            "Infobox": "",
+            "RelatedTopics": [],
+            "Type": ""
+        }"#;
+        let resp: InstantAnswerResponse = serde_json::from_str(json).unwrap();
+        assert!(resp.infobox.is_none());
+    }
+
+    #[test]
+    fn test_response_type_label() {
+        let mut resp = InstantAnswerResponse {
+            response_type: "D".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(resp.response_type_label(), Some("disambiguation"));
+        resp.response_type.clear();
+        assert_eq!(resp.response_type_label(), None);
+    }
+
+    #[test]
+    fn test_disambiguation_skip_disambig_value() {
+        assert_eq!(Disambiguation::default(), Disambiguation::Skip);
+        assert_eq!(Disambiguation::Skip.skip_disambig_value(), "1");
+        assert_eq!(Disambiguation::List.skip_disambig_value(), "0");
+    }
+
+    #[test]
+    fn test_image_url_keeps_absolute_and_skips_empty() {
+        let mut resp = InstantAnswerResponse {
+            image: "https://example.com/logo.png".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            resp.image_url().as_deref(),
+            Some("https://example.com/logo.png")
+        );
+        resp.image.clear();
+        assert_eq!(resp.image_url(), None);
+    }
 }
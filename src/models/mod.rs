@@ -1,2 +1,9 @@
+pub mod brave;
+pub mod images;
 pub mod instant_answer;
+pub mod news;
 pub mod search;
+pub mod searxng;
+pub mod suggestions;
+pub mod text;
+pub mod videos;
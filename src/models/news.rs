@@ -0,0 +1,128 @@
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::models::text::strip_html;
+
+#[derive(Debug, Deserialize)]
+pub struct NewsResponse {
+    #[serde(default)]
+    pub results: Vec<NewsResult>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NewsResult {
+    #[serde(default)]
+    pub date: i64,
+    pub title: String,
+    #[serde(default)]
+    pub excerpt: String,
+    pub url: String,
+    #[serde(default)]
+    pub source: String,
+    #[serde(default)]
+    pub image: Option<String>,
+    #[serde(default)]
+    pub relative_time: Option<String>,
+}
+
+impl NewsResult {
+    pub fn published_at(&self) -> Option<String> {
+        if self.date <= 0 {
+            return None;
+        }
+        DateTime::from_timestamp(self.date, 0).map(|dt| dt.format("%Y-%m-%d %H:%M UTC").to_string())
+    }
+}
+
+pub fn format_news_markdown(query: &str, results: &[NewsResult]) -> String {
+    let mut output = format!("## News Results for \"{query}\"\n\n");
+
+    if results.is_empty() {
+        output.push_str("No news found.\n\n");
+        output.push_str("_Source: DuckDuckGo News_");
+        return output;
+    }
+
+    for (i, result) in results.iter().enumerate() {
+        output.push_str(&format!("### {}. {}\n", i + 1, strip_html(&result.title)));
+        if !result.source.is_empty() {
+            output.push_str(&format!("**Source:** {}\n", result.source));
+        }
+        match (result.published_at(), &result.relative_time) {
+            (Some(date), Some(relative)) => {
+                output.push_str(&format!("**Published:** {} ({})\n", date, relative))
+            }
+            (Some(date), None) => output.push_str(&format!("**Published:** {}\n", date)),
+            (None, Some(relative)) => output.push_str(&format!("**Published:** {}\n", relative)),
+            (None, None) => {}
+        }
+        output.push_str(&format!("**URL:** {}\n", result.url));
+        if let Some(image) = result.image.as_deref().filter(|image| !image.is_empty()) {
+            output.push_str(&format!("**Image:** {}\n", image));
+        }
+        let excerpt = strip_html(&result.excerpt);
+        if !excerpt.is_empty() {
+            output.push_str(&format!("{}\n", excerpt));
+        }
+        output.push_str("\n---\n\n");
+    }
+
+    output.push_str(&format!(
+        "_Source: DuckDuckGo News ({} results)_",
+        results.len()
+    ));
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> NewsResponse {
+        serde_json::from_str(include_str!("../../tests/fixtures/news_results.json")).unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_news_fixture() {
+        let response = fixture();
+        assert_eq!(response.results.len(), 3);
+        assert_eq!(response.results[0].source, "The Register");
+        assert!(response.results[2].image.is_none());
+    }
+
+    #[test]
+    fn test_published_at_formats_unix_timestamp() {
+        let result = NewsResult {
+            date: 1_714_651_200,
+            title: String::new(),
+            excerpt: String::new(),
+            url: String::new(),
+            source: String::new(),
+            image: None,
+            relative_time: None,
+        };
+        assert_eq!(
+            result.published_at().as_deref(),
+            Some("2024-05-02 12:00 UTC")
+        );
+    }
+
+    #[test]
+    fn test_format_news_markdown() {
+        let output = format_news_markdown("rust", &fixture().results);
+        assert!(output.starts_with("## News Results for \"rust\"\n\n"));
+        assert!(output.contains("### 1. Rust 1.78 lands with diagnostic attributes\n"));
+        assert!(output.contains("**Source:** The Register\n"));
+        assert!(output.contains("**Published:** 2024-05-02 12:00 UTC (2 hours ago)\n"));
+        assert!(output.contains("**Image:** https://images.example/rust-178.jpg\n"));
+        assert!(output.contains("The Rust team has shipped 1.78.\n"));
+        assert!(output.ends_with("_Source: DuckDuckGo News (3 results)_"));
+    }
+
+    #[test]
+    fn test_format_news_markdown_empty() {
+        let output = format_news_markdown("nothing", &[]);
+        assert!(output.contains("No news found."));
+        assert!(output.ends_with("_Source: DuckDuckGo News_"));
+    }
+}
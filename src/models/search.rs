@@ -1,22 +1,372 @@
-#[derive(Debug, Clone)]
+use std::str::FromStr;
+
+use reqwest::Url;
+use rmcp::model::{CallToolResult, Content};
+use rmcp::schemars;
+use serde::{Deserialize, Serialize};
+
+const SECOND_LEVEL_LABELS: [&str; 10] = [
+    "ac", "co", "com", "edu", "go", "gov", "ne", "net", "or", "org",
+];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SearchResult {
     pub title: String,
     pub url: String,
     pub snippet: String,
+    pub display_url: Option<String>,
+    pub domain: Option<String>,
+    pub position: Option<usize>,
+    pub favicon_url: Option<String>,
+    pub tracking_url: Option<String>,
+    pub engines: Vec<String>,
+    pub date: Option<String>,
+    pub extra_snippets: Vec<String>,
+    pub sources: Vec<String>,
+    pub is_ad: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Infobox {
+    pub title: String,
+    pub content: String,
+    pub attributes: Vec<(String, String)>,
+    pub links: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchResponse {
+    pub results: Vec<SearchResult>,
+    pub infoboxes: Vec<Infobox>,
+    pub suggestions: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+/// Returned by `web_search` as `structured_content` next to the Markdown text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchOutput {
+    pub query: String,
+    pub source: String,
+    #[serde(flatten)]
+    pub response: SearchResponse,
+}
+
+impl SearchOutput {
+    pub fn to_markdown(&self) -> String {
+        format_response_markdown(&self.query, &self.response, &self.source)
+    }
+
+    pub fn to_tool_result(&self) -> CallToolResult {
+        let mut result = CallToolResult::success(vec![Content::text(self.to_markdown())]);
+        result.structured_content = serde_json::to_value(self).ok();
+        result
+    }
+}
+
+impl From<Vec<SearchResult>> for SearchResponse {
+    fn from(results: Vec<SearchResult>) -> Self {
+        Self {
+            results,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SafeSearch {
+    Strict,
+    #[default]
+    Moderate,
+    Off,
+}
+
+impl SafeSearch {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Strict => "strict",
+            Self::Moderate => "moderate",
+            Self::Off => "off",
+        }
+    }
+
+    pub fn kp_value(&self) -> &'static str {
+        match self {
+            Self::Strict => "1",
+            Self::Moderate => "-1",
+            Self::Off => "-2",
+        }
+    }
+}
+
+impl FromStr for SafeSearch {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "strict" => Ok(Self::Strict),
+            "moderate" => Ok(Self::Moderate),
+            "off" => Ok(Self::Off),
+            other => Err(format!("unknown safe search level: {other}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeRange {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl TimeRange {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+            Self::Year => "year",
+        }
+    }
+
+    pub fn df_value(&self) -> &'static str {
+        match self {
+            Self::Day => "d",
+            Self::Week => "w",
+            Self::Month => "m",
+            Self::Year => "y",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchOptions {
+    pub max_results: usize,
+    pub page: usize,
+    pub region: Option<String>,
+    pub safe_search: SafeSearch,
+    pub time_range: Option<TimeRange>,
+    pub include_domains: Vec<String>,
+    pub exclude_domains: Vec<String>,
+    pub include_ads: bool,
+}
+
+impl SearchOptions {
+    pub fn cache_key(&self, query: &str) -> String {
+        format!(
+            "{}:{}:{}:{}:{}:{}:{}:{}:{}",
+            query,
+            self.max_results,
+            self.page,
+            self.region.as_deref().unwrap_or_default(),
+            self.safe_search.as_str(),
+            self.time_range.map(|t| t.as_str()).unwrap_or_default(),
+            self.include_domains.join(","),
+            self.exclude_domains.join(","),
+            if self.include_ads { "ads" } else { "" }
+        )
+    }
+
+    pub fn query_with_site_operators(&self, query: &str) -> String {
+        let mut output = query.to_string();
+        match self.include_domains.as_slice() {
+            [] => {}
+            [domain] => output.push_str(&format!(" site:{domain}")),
+            domains => {
+                let sites: Vec<String> = domains.iter().map(|d| format!("site:{d}")).collect();
+                output.push_str(&format!(" ({})", sites.join(" OR ")));
+            }
+        }
+        for domain in &self.exclude_domains {
+            output.push_str(&format!(" -site:{domain}"));
+        }
+        output
+    }
+
+    pub fn allows_url(&self, url: &str) -> bool {
+        if self.include_domains.is_empty() && self.exclude_domains.is_empty() {
+            return true;
+        }
+        let Some(host) = Url::parse(url).ok().and_then(|u| {
+            u.host_str()
+                .map(|h| h.trim_end_matches('.').to_ascii_lowercase())
+        }) else {
+            return self.include_domains.is_empty();
+        };
+        let matches = |domain: &String| host == *domain || host.ends_with(&format!(".{domain}"));
+        (self.include_domains.is_empty() || self.include_domains.iter().any(matches))
+            && !self.exclude_domains.iter().any(matches)
+    }
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            max_results: 10,
+            page: 1,
+            region: None,
+            safe_search: SafeSearch::default(),
+            time_range: None,
+            include_domains: Vec::new(),
+            exclude_domains: Vec::new(),
+            include_ads: false,
+        }
+    }
+}
+
+pub fn registrable_domain(url: &str) -> Option<String> {
+    let parsed = Url::parse(url).ok()?;
+    let host = parsed
+        .host_str()?
+        .trim_end_matches('.')
+        .to_ascii_lowercase();
+    if parsed.domain().is_none() {
+        return Some(host);
+    }
+    let labels: Vec<&str> = host.split('.').collect();
+    let keep = match labels.as_slice() {
+        [.., second, tld] if tld.len() == 2 && SECOND_LEVEL_LABELS.contains(second) => 3,
+        _ => 2,
+    };
+    Some(labels[labels.len().saturating_sub(keep)..].join("."))
+}
+
+pub fn region_locale(region: &str) -> Option<(String, String)> {
+    let (country, language) = region.split_once('-')?;
+    if country == "wt" || country.is_empty() || language.is_empty() {
+        return None;
+    }
+    let language = match language {
+        "jp" => "ja",
+        "kr" => "ko",
+        "tzh" => "zh",
+        other => other,
+    };
+    let country = match country {
+        "uk" => "gb",
+        other => other,
+    };
+    Some((language.to_string(), country.to_ascii_uppercase()))
+}
+
+pub fn normalize_domains(domains: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = domains
+        .iter()
+        .filter_map(|domain| {
+            let domain = domain.trim().to_ascii_lowercase();
+            let domain = domain
+                .split_once("://")
+                .map(|(_, rest)| rest.to_string())
+                .unwrap_or(domain);
+            let domain = domain
+                .split('/')
+                .next()
+                .unwrap_or_default()
+                .trim_start_matches("*.")
+                .trim_matches('.')
+                .to_string();
+            (!domain.is_empty()).then_some(domain)
+        })
+        .collect();
+    normalized.sort();
+    normalized.dedup();
+    normalized
 }
 
-pub fn format_results_markdown(query: &str, results: &[SearchResult]) -> String {
+pub fn format_results_markdown(query: &str, results: &[SearchResult], source: &str) -> String {
+    format_response_markdown(query, &SearchResponse::from(results.to_vec()), source)
+}
+
+pub fn format_response_markdown(query: &str, response: &SearchResponse, source: &str) -> String {
     let mut output = format!("## Web Search Results for \"{query}\"\n\n");
-    if results.is_empty() {
-        output.push_str("No results found.\n\n_Source: DuckDuckGo_");
-        return output;
+
+    for infobox in &response.infoboxes {
+        output.push_str(&format!("### Infobox: {}\n", infobox.title));
+        if !infobox.content.is_empty() {
+            output.push_str(&format!("{}\n", infobox.content));
+        }
+        for (label, value) in &infobox.attributes {
+            output.push_str(&format!("- **{}:** {}\n", label, value));
+        }
+        if !infobox.links.is_empty() {
+            let links: Vec<String> = infobox
+                .links
+                .iter()
+                .map(|(title, url)| format!("[{title}]({url})"))
+                .collect();
+            output.push_str(&format!("**Links:** {}\n", links.join(", ")));
+        }
+        output.push_str("\n---\n\n");
     }
-    for (i, result) in results.iter().enumerate() {
+
+    let (ads, organic): (Vec<&SearchResult>, Vec<&SearchResult>) =
+        response.results.iter().partition(|result| result.is_ad);
+
+    if organic.is_empty() {
+        output.push_str("No results found.\n\n");
+    }
+    for (i, result) in organic.iter().enumerate() {
         output.push_str(&format!("### {}. {}\n", i + 1, result.title));
         output.push_str(&format!("**URL:** {}\n", result.url));
-        output.push_str(&format!("{}\n\n---\n\n", result.snippet));
+        if let Some(display_url) = &result.display_url {
+            output.push_str(&format!("**Display URL:** {}\n", display_url));
+        }
+        if let Some(domain) = &result.domain {
+            output.push_str(&format!("**Domain:** {}\n", domain));
+        }
+        if let Some(position) = result.position {
+            output.push_str(&format!("**Rank:** {}\n", position));
+        }
+        if let Some(favicon_url) = &result.favicon_url {
+            output.push_str(&format!("**Favicon:** {}\n", favicon_url));
+        }
+        if let Some(date) = &result.date {
+            output.push_str(&format!("**Date:** {}\n", date));
+        }
+        if !result.sources.is_empty() {
+            output.push_str(&format!("**Found by:** {}\n", result.sources.join(", ")));
+        }
+        if !result.engines.is_empty() {
+            output.push_str(&format!("**Engines:** {}\n", result.engines.join(", ")));
+        }
+        output.push_str(&format!("{}\n", result.snippet));
+        for extra in &result.extra_snippets {
+            output.push_str(&format!("- {}\n", extra));
+        }
+        output.push_str("\n---\n\n");
+    }
+
+    if !ads.is_empty() {
+        output.push_str("### Sponsored\n_Advertisements, not organic search results._\n\n");
+        for ad in &ads {
+            output.push_str(&format!("- **[{}]({})**", ad.title, ad.url));
+            if !ad.snippet.is_empty() {
+                output.push_str(&format!(": {}", ad.snippet));
+            }
+            output.push('\n');
+        }
+        output.push('\n');
+    }
+
+    if !response.suggestions.is_empty() {
+        output.push_str("### Suggestions\n");
+        for suggestion in &response.suggestions {
+            output.push_str(&format!("- {}\n", suggestion));
+        }
+        output.push('\n');
+    }
+
+    for warning in &response.warnings {
+        output.push_str(&format!("> **Warning:** {}\n\n", warning));
+    }
+
+    if organic.is_empty() {
+        output.push_str(&format!("_Source: {source}_"));
+    } else {
+        output.push_str(&format!("_Source: {} ({} results)_", source, organic.len()));
     }
-    output.push_str(&format!("_Source: DuckDuckGo ({} results)_", results.len()));
     output
 }
 
@@ -30,12 +380,168 @@ mod tests {
             title: "Rust Programming".to_string(),
             url: "https://www.rust-lang.org/".to_string(),
             snippet: "A systems programming language".to_string(),
+            ..Default::default()
         };
         assert_eq!(result.title, "Rust Programming");
         assert_eq!(result.url, "https://www.rust-lang.org/");
         assert_eq!(result.snippet, "A systems programming language");
     }
 
+    #[test]
+    fn test_search_options_default_starts_at_first_page() {
+        let options = SearchOptions::default();
+        assert_eq!(options.page, 1);
+        assert_eq!(options.max_results, 10);
+    }
+
+    #[test]
+    fn test_safe_search_kp_values() {
+        assert_eq!(SafeSearch::Strict.kp_value(), "1");
+        assert_eq!(SafeSearch::Moderate.kp_value(), "-1");
+        assert_eq!(SafeSearch::Off.kp_value(), "-2");
+    }
+
+    #[test]
+    fn test_safe_search_from_str() {
+        assert_eq!("strict".parse::<SafeSearch>(), Ok(SafeSearch::Strict));
+        assert_eq!(" OFF ".parse::<SafeSearch>(), Ok(SafeSearch::Off));
+        assert!("none".parse::<SafeSearch>().is_err());
+    }
+
+    #[test]
+    fn test_cache_key_distinguishes_region_and_safe_search() {
+        let default = SearchOptions::default();
+        let japan = SearchOptions {
+            region: Some("jp-jp".to_string()),
+            ..Default::default()
+        };
+        let strict = SearchOptions {
+            safe_search: SafeSearch::Strict,
+            ..Default::default()
+        };
+        assert_ne!(default.cache_key("rust"), japan.cache_key("rust"));
+        assert_ne!(default.cache_key("rust"), strict.cache_key("rust"));
+    }
+
+    #[test]
+    fn test_time_range_df_values() {
+        assert_eq!(TimeRange::Day.df_value(), "d");
+        assert_eq!(TimeRange::Week.df_value(), "w");
+        assert_eq!(TimeRange::Month.df_value(), "m");
+        assert_eq!(TimeRange::Year.df_value(), "y");
+    }
+
+    #[test]
+    fn test_cache_key_distinguishes_time_range() {
+        let unfiltered = SearchOptions::default();
+        let past_week = SearchOptions {
+            time_range: Some(TimeRange::Week),
+            ..Default::default()
+        };
+        assert_ne!(unfiltered.cache_key("cve"), past_week.cache_key("cve"));
+    }
+
+    fn domains(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_query_with_single_include_domain() {
+        let options = SearchOptions {
+            include_domains: domains(&["docs.rs"]),
+            ..Default::default()
+        };
+        assert_eq!(
+            options.query_with_site_operators("serde"),
+            "serde site:docs.rs"
+        );
+    }
+
+    #[test]
+    fn test_query_with_multiple_include_and_exclude_domains() {
+        let options = SearchOptions {
+            include_domains: domains(&["docs.rs", "github.com"]),
+            exclude_domains: domains(&["pinterest.com"]),
+            ..Default::default()
+        };
+        assert_eq!(
+            options.query_with_site_operators("serde"),
+            "serde (site:docs.rs OR site:github.com) -site:pinterest.com"
+        );
+    }
+
+    #[test]
+    fn test_allows_url_matches_subdomains() {
+        let options = SearchOptions {
+            include_domains: domains(&["github.com"]),
+            ..Default::default()
+        };
+        assert!(options.allows_url("https://github.com/serde-rs/serde"));
+        assert!(options.allows_url("https://gist.github.com/abc"));
+        assert!(!options.allows_url("https://notgithub.com/"));
+        assert!(!options.allows_url("https://docs.rs/serde"));
+    }
+
+    #[test]
+    fn test_allows_url_excludes_domains() {
+        let options = SearchOptions {
+            exclude_domains: domains(&["pinterest.com"]),
+            ..Default::default()
+        };
+        assert!(!options.allows_url("https://www.pinterest.com/pin/1"));
+        assert!(options.allows_url("https://example.com/"));
+    }
+
+    #[test]
+    fn test_allows_url_without_filters() {
+        let options = SearchOptions::default();
+        assert!(options.allows_url("not a url"));
+    }
+
+    #[test]
+    fn test_region_locale() {
+        assert_eq!(
+            region_locale("jp-jp"),
+            Some(("ja".to_string(), "JP".to_string()))
+        );
+        assert_eq!(
+            region_locale("uk-en"),
+            Some(("en".to_string(), "GB".to_string()))
+        );
+        assert_eq!(region_locale("wt-wt"), None);
+        assert_eq!(region_locale("jp"), None);
+    }
+
+    #[test]
+    fn test_normalize_domains() {
+        let normalized = normalize_domains(&domains(&[
+            " GitHub.com ",
+            "https://docs.rs/serde",
+            "*.example.org",
+            "",
+            "github.com",
+        ]));
+        assert_eq!(
+            normalized,
+            domains(&["docs.rs", "example.org", "github.com"])
+        );
+    }
+
+    #[test]
+    fn test_cache_key_distinguishes_domain_filters() {
+        let unfiltered = SearchOptions::default();
+        let included = SearchOptions {
+            include_domains: domains(&["docs.rs"]),
+            ..Default::default()
+        };
+        let excluded = SearchOptions {
+            exclude_domains: domains(&["docs.rs"]),
+            ..Default::default()
+        };
+        assert_ne!(unfiltered.cache_key("serde"), included.cache_key("serde"));
+        assert_ne!(included.cache_key("serde"), excluded.cache_key("serde"));
+    }
+
     #[test]
     fn test_format_results_markdown() {
         let results = vec![
@@ -43,14 +549,16 @@ mod tests {
                 title: "The Rust Programming Language".to_string(),
                 url: "https://www.rust-lang.org/".to_string(),
                 snippet: "Rust is a systems programming language.".to_string(),
+                ..Default::default()
             },
             SearchResult {
                 title: "Rust - Wikipedia".to_string(),
                 url: "https://en.wikipedia.org/wiki/Rust".to_string(),
                 snippet: "Rust is a multi-paradigm language.".to_string(),
+                ..Default::default()
             },
         ];
-        let output = format_results_markdown("Rust programming", &results);
+        let output = format_results_markdown("Rust programming", &results, "DuckDuckGo");
         assert!(output.contains("## Web Search Results for \"Rust programming\""));
         assert!(output.contains("### 1. The Rust Programming Language"));
         assert!(output.contains("**URL:** https://www.rust-lang.org/"));
@@ -61,8 +569,193 @@ mod tests {
     #[test]
     fn test_format_results_markdown_empty() {
         let results: Vec<SearchResult> = vec![];
-        let output = format_results_markdown("no results query", &results);
+        let output = format_results_markdown("no results query", &results, "DuckDuckGo");
         assert!(output.contains("No results found."));
         assert!(output.contains("_Source: DuckDuckGo_"));
     }
+
+    #[test]
+    fn test_format_response_markdown_renders_infobox_engines_and_suggestions() {
+        let response = SearchResponse {
+            results: vec![SearchResult {
+                title: "Rust".to_string(),
+                url: "https://www.rust-lang.org/".to_string(),
+                snippet: "A language empowering everyone.".to_string(),
+                engines: vec!["google".to_string(), "bing".to_string()],
+                ..Default::default()
+            }],
+            infoboxes: vec![Infobox {
+                title: "Rust".to_string(),
+                content: "Rust is a programming language.".to_string(),
+                attributes: vec![("Designed by".to_string(), "Graydon Hoare".to_string())],
+                links: vec![(
+                    "Official website".to_string(),
+                    "https://www.rust-lang.org/".to_string(),
+                )],
+            }],
+            suggestions: vec!["rust book".to_string()],
+            ..Default::default()
+        };
+        let output = format_response_markdown("rust", &response, "SearXNG");
+        assert!(output.contains("### Infobox: Rust"));
+        assert!(output.contains("- **Designed by:** Graydon Hoare"));
+        assert!(output.contains("**Links:** [Official website](https://www.rust-lang.org/)"));
+        assert!(output.contains("**Engines:** google, bing"));
+        assert!(output.contains("### Suggestions\n- rust book"));
+        assert!(output.contains("_Source: SearXNG (1 results)_"));
+    }
+
+    #[test]
+    fn test_format_results_markdown_renders_date_and_extra_snippets() {
+        let results = vec![SearchResult {
+            title: "Rust 1.90 released".to_string(),
+            url: "https://blog.rust-lang.org/".to_string(),
+            snippet: "The Rust team is happy to announce a new version.".to_string(),
+            date: Some("2 days ago".to_string()),
+            extra_snippets: vec!["Stabilized APIs".to_string()],
+            ..Default::default()
+        }];
+        let output = format_results_markdown("rust release", &results, "Brave Search");
+        assert!(output.contains("**Date:** 2 days ago\n"));
+        assert!(output.contains("a new version.\n- Stabilized APIs\n"));
+    }
+
+    #[test]
+    fn test_format_response_markdown_renders_provenance_and_warnings() {
+        let response = SearchResponse {
+            results: vec![SearchResult {
+                title: "Rust".to_string(),
+                url: "https://www.rust-lang.org/".to_string(),
+                sources: vec!["duckduckgo".to_string(), "brave".to_string()],
+                ..Default::default()
+            }],
+            warnings: vec!["searxng failed: Request timed out. Please try again.".to_string()],
+            ..Default::default()
+        };
+        let output = format_response_markdown("rust", &response, "DuckDuckGo, Brave Search");
+        assert!(output.contains("**Found by:** duckduckgo, brave\n"));
+        assert!(output.contains("> **Warning:** searxng failed: Request timed out."));
+        assert!(output.ends_with("_Source: DuckDuckGo, Brave Search (1 results)_"));
+    }
+
+    #[test]
+    fn test_format_results_markdown_separates_sponsored_results() {
+        let results = vec![
+            SearchResult {
+                title: "Buy Rust Hosting".to_string(),
+                url: "https://ads.example/rust".to_string(),
+                snippet: "Fast servers.".to_string(),
+                is_ad: true,
+                ..Default::default()
+            },
+            SearchResult {
+                title: "Rust".to_string(),
+                url: "https://www.rust-lang.org/".to_string(),
+                ..Default::default()
+            },
+        ];
+        let output = format_results_markdown("rust", &results, "DuckDuckGo");
+        assert!(output.contains("### 1. Rust\n"));
+        assert!(!output.contains("### 2."));
+        assert!(output.contains(
+            "### Sponsored\n_Advertisements, not organic search results._\n\n- **[Buy Rust Hosting](https://ads.example/rust)**: Fast servers.\n"
+        ));
+        assert!(output.ends_with("_Source: DuckDuckGo (1 results)_"));
+    }
+
+    #[test]
+    fn test_registrable_domain() {
+        assert_eq!(
+            registrable_domain("https://www.rust-lang.org/learn").as_deref(),
+            Some("rust-lang.org")
+        );
+        assert_eq!(
+            registrable_domain("https://news.bbc.co.uk/").as_deref(),
+            Some("bbc.co.uk")
+        );
+        assert_eq!(
+            registrable_domain("https://www.example.co.jp/").as_deref(),
+            Some("example.co.jp")
+        );
+        assert_eq!(
+            registrable_domain("https://docs.rs/serde").as_deref(),
+            Some("docs.rs")
+        );
+        assert_eq!(
+            registrable_domain("http://127.0.0.1:8080/").as_deref(),
+            Some("127.0.0.1")
+        );
+        assert_eq!(registrable_domain("not a url"), None);
+    }
+
+    #[test]
+    fn test_format_results_markdown_renders_metadata() {
+        let results = vec![SearchResult {
+            title: "Rust".to_string(),
+            url: "https://www.rust-lang.org/".to_string(),
+            display_url: Some("www.rust-lang.org".to_string()),
+            domain: Some("rust-lang.org".to_string()),
+            position: Some(3),
+            favicon_url: Some(
+                "https://external-content.duckduckgo.com/ip3/www.rust-lang.org.ico".to_string(),
+            ),
+            ..Default::default()
+        }];
+        let output = format_results_markdown("rust", &results, "DuckDuckGo");
+        assert!(output.contains(
+            "**URL:** https://www.rust-lang.org/\n**Display URL:** www.rust-lang.org\n**Domain:** rust-lang.org\n**Rank:** 3\n**Favicon:** https://external-content.duckduckgo.com/ip3/www.rust-lang.org.ico\n"
+        ));
+    }
+
+    #[test]
+    fn test_search_result_serializes_metadata() {
+        let result = SearchResult {
+            title: "Rust".to_string(),
+            url: "https://www.rust-lang.org/".to_string(),
+            domain: Some("rust-lang.org".to_string()),
+            position: Some(1),
+            date: Some("2024-05-02".to_string()),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["domain"], "rust-lang.org");
+        assert_eq!(json["position"], 1);
+        assert_eq!(json["date"], "2024-05-02");
+        assert!(json["favicon_url"].is_null());
+    }
+
+    #[test]
+    fn test_cache_key_includes_ads_flag() {
+        let with_ads = SearchOptions {
+            include_ads: true,
+            ..Default::default()
+        };
+        assert_ne!(
+            with_ads.cache_key("rust"),
+            SearchOptions::default().cache_key("rust")
+        );
+    }
+
+    #[test]
+    fn test_format_response_markdown_suggestions_without_results() {
+        let response = SearchResponse {
+            suggestions: vec!["rust lang".to_string()],
+            ..Default::default()
+        };
+        let output = format_response_markdown("rsut", &response, "SearXNG");
+        assert!(output.contains("No results found."));
+        assert!(output.contains("- rust lang"));
+        assert!(output.ends_with("_Source: SearXNG_"));
+    }
+
+    #[test]
+    fn test_format_results_markdown_uses_source() {
+        let results = vec![SearchResult {
+            title: "Example".to_string(),
+            url: "https://example.com/".to_string(),
+            ..Default::default()
+        }];
+        let output = format_results_markdown("example", &results, "SearXNG");
+        assert!(output.contains("_Source: SearXNG (1 results)_"));
+    }
 }
@@ -0,0 +1,146 @@
+use serde::Deserialize;
+
+use crate::models::search::{registrable_domain, Infobox, SearchResult};
+
+#[derive(Debug, Deserialize)]
+pub struct SearxngResponse {
+    #[serde(default)]
+    pub results: Vec<SearxngResult>,
+    #[serde(default)]
+    pub infoboxes: Vec<SearxngInfobox>,
+    #[serde(default)]
+    pub suggestions: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearxngResult {
+    pub url: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub content: Option<String>,
+    #[serde(default)]
+    pub engine: Option<String>,
+    #[serde(default)]
+    pub engines: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearxngInfobox {
+    pub infobox: String,
+    #[serde(default)]
+    pub content: Option<String>,
+    #[serde(default)]
+    pub attributes: Vec<SearxngAttribute>,
+    #[serde(default)]
+    pub urls: Vec<SearxngUrl>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearxngAttribute {
+    pub label: String,
+    #[serde(default)]
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearxngUrl {
+    pub title: String,
+    pub url: String,
+}
+
+impl From<SearxngResult> for SearchResult {
+    fn from(result: SearxngResult) -> Self {
+        let engines = if result.engines.is_empty() {
+            result.engine.into_iter().collect()
+        } else {
+            result.engines
+        };
+        Self {
+            title: result.title.trim().to_string(),
+            domain: registrable_domain(&result.url),
+            url: result.url,
+            snippet: result.content.unwrap_or_default().trim().to_string(),
+            engines,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<SearxngInfobox> for Infobox {
+    fn from(infobox: SearxngInfobox) -> Self {
+        Self {
+            title: infobox.infobox,
+            content: infobox.content.unwrap_or_default().trim().to_string(),
+            attributes: infobox
+                .attributes
+                .into_iter()
+                .filter_map(|attr| match attr.value {
+                    serde_json::Value::String(value) => Some((attr.label, value)),
+                    serde_json::Value::Number(value) => Some((attr.label, value.to_string())),
+                    _ => None,
+                })
+                .collect(),
+            links: infobox
+                .urls
+                .into_iter()
+                .map(|link| (link.title, link.url))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_fixture() {
+        let json = include_str!("../../tests/fixtures/searxng_results.json");
+        let resp: SearxngResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.results.len(), 3);
+        assert_eq!(resp.infoboxes.len(), 1);
+        assert_eq!(
+            resp.suggestions,
+            vec!["rust book", "rust programming language"]
+        );
+    }
+
+    #[test]
+    fn test_deserialize_minimal_response() {
+        let resp: SearxngResponse = serde_json::from_str(r#"{"query": "x"}"#).unwrap();
+        assert!(resp.results.is_empty());
+        assert!(resp.infoboxes.is_empty());
+        assert!(resp.suggestions.is_empty());
+    }
+
+    #[test]
+    fn test_result_falls_back_to_single_engine() {
+        let result: SearxngResult = serde_json::from_str(
+            r#"{"url": "https://example.com", "title": "Example", "content": null, "engine": "wikipedia"}"#,
+        )
+        .unwrap();
+        let result = SearchResult::from(result);
+        assert_eq!(result.engines, vec!["wikipedia"]);
+        assert_eq!(result.snippet, "");
+    }
+
+    #[test]
+    fn test_infobox_skips_non_text_attributes() {
+        let infobox: SearxngInfobox = serde_json::from_str(
+            r#"{
+                "infobox": "Rust",
+                "attributes": [
+                    {"label": "Developer", "value": "Rust Foundation"},
+                    {"label": "Logo", "value": {"src": "https://example.com/logo.png"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let infobox = Infobox::from(infobox);
+        assert_eq!(
+            infobox.attributes,
+            vec![("Developer".to_string(), "Rust Foundation".to_string())]
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Suggestion {
+    #[serde(default)]
+    pub phrase: String,
+}
+
+pub fn format_suggestions_markdown(query: &str, suggestions: &[String]) -> String {
+    let mut output = format!("## Search Suggestions for \"{query}\"\n\n");
+
+    if suggestions.is_empty() {
+        output.push_str("No suggestions found.\n\n");
+    } else {
+        for (i, suggestion) in suggestions.iter().enumerate() {
+            output.push_str(&format!("{}. {}\n", i + 1, suggestion));
+        }
+        output.push('\n');
+    }
+
+    output.push_str("_Source: DuckDuckGo Autocomplete_");
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_suggestions_fixture() {
+        let suggestions: Vec<Suggestion> =
+            serde_json::from_str(include_str!("../../tests/fixtures/suggestions.json")).unwrap();
+        assert_eq!(suggestions.len(), 6);
+        assert_eq!(suggestions[0].phrase, "rust");
+    }
+
+    #[test]
+    fn test_format_suggestions_markdown() {
+        let output = format_suggestions_markdown(
+            "rus",
+            &["rust".to_string(), "rust programming".to_string()],
+        );
+        assert!(output.starts_with("## Search Suggestions for \"rus\"\n\n"));
+        assert!(output.contains("1. rust\n2. rust programming\n"));
+        assert!(output.ends_with("_Source: DuckDuckGo Autocomplete_"));
+    }
+
+    #[test]
+    fn test_format_suggestions_markdown_empty() {
+        let output = format_suggestions_markdown("xyzzy", &[]);
+        assert!(output.contains("No suggestions found."));
+    }
+}
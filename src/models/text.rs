@@ -0,0 +1,24 @@
+use scraper::Html;
+
+/// Removes inline markup (e.g. `<b>` highlights) and decodes entities in API text fields.
+pub fn strip_html(text: &str) -> String {
+    Html::parse_fragment(text)
+        .root_element()
+        .text()
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_html_removes_tags_and_decodes_entities() {
+        assert_eq!(
+            strip_html("The <strong>Rust</strong> &amp; Cargo book"),
+            "The Rust & Cargo book"
+        );
+    }
+}
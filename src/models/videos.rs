@@ -0,0 +1,228 @@
+use rmcp::schemars;
+use serde::{Deserialize, Serialize};
+
+use crate::models::text::strip_html;
+
+#[derive(Debug, Deserialize)]
+pub struct VideoResponse {
+    #[serde(default)]
+    pub results: Vec<VideoResult>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct VideoResult {
+    pub title: String,
+    /// Watch page URL
+    pub content: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub duration: String,
+    #[serde(default)]
+    pub embed_url: String,
+    #[serde(default)]
+    pub images: VideoImages,
+    #[serde(default)]
+    pub published: String,
+    #[serde(default)]
+    pub publisher: String,
+    #[serde(default)]
+    pub uploader: String,
+    #[serde(default)]
+    pub statistics: VideoStatistics,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct VideoImages {
+    #[serde(default)]
+    pub small: String,
+    #[serde(default)]
+    pub medium: String,
+    #[serde(default)]
+    pub large: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct VideoStatistics {
+    #[serde(rename = "viewCount", default)]
+    pub view_count: Option<u64>,
+}
+
+impl VideoResult {
+    /// Upload date as `YYYY-MM-DD` (DDG sends `2023-04-12T14:00:11.0000000`).
+    pub fn upload_date(&self) -> Option<&str> {
+        let date = self.published.get(..10)?;
+        chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .ok()
+            .map(|_| date)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoDuration {
+    /// Under 4 minutes
+    Short,
+    /// 4 to 20 minutes
+    Medium,
+    /// Over 20 minutes
+    Long,
+}
+
+impl VideoDuration {
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            Self::Short => "short",
+            Self::Medium => "medium",
+            Self::Long => "long",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoResolution {
+    High,
+    Standard,
+}
+
+impl VideoResolution {
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            Self::High => "high",
+            Self::Standard => "standard",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VideoFilters {
+    pub duration: Option<VideoDuration>,
+    pub resolution: Option<VideoResolution>,
+}
+
+impl VideoFilters {
+    /// Positional `f` value: `publishedAfter,videoDefinition,videoDuration,videoLicense`.
+    pub fn f_param(&self) -> String {
+        format!(
+            ",{},{},",
+            self.resolution
+                .map(|r| format!("videoDefinition:{}", r.as_param()))
+                .unwrap_or_default(),
+            self.duration
+                .map(|d| format!("videoDuration:{}", d.as_param()))
+                .unwrap_or_default(),
+        )
+    }
+}
+
+pub fn format_videos_markdown(query: &str, results: &[VideoResult]) -> String {
+    let mut output = format!("## Video Results for \"{query}\"\n\n");
+
+    if results.is_empty() {
+        output.push_str("No videos found.\n\n");
+        output.push_str("_Source: DuckDuckGo Videos_");
+        return output;
+    }
+
+    for (i, result) in results.iter().enumerate() {
+        output.push_str(&format!("### {}. {}\n", i + 1, strip_html(&result.title)));
+        output.push_str(&format!("**URL:** {}\n", result.content));
+        match (result.publisher.is_empty(), result.uploader.is_empty()) {
+            (false, false) => output.push_str(&format!(
+                "**Publisher:** {} ({})\n",
+                result.publisher, result.uploader
+            )),
+            (false, true) => output.push_str(&format!("**Publisher:** {}\n", result.publisher)),
+            (true, false) => output.push_str(&format!("**Publisher:** {}\n", result.uploader)),
+            (true, true) => {}
+        }
+        if !result.duration.is_empty() {
+            output.push_str(&format!("**Duration:** {}\n", result.duration));
+        }
+        if let Some(date) = result.upload_date() {
+            output.push_str(&format!("**Uploaded:** {}\n", date));
+        }
+        if let Some(views) = result.statistics.view_count {
+            output.push_str(&format!("**Views:** {}\n", views));
+        }
+        if !result.embed_url.is_empty() {
+            output.push_str(&format!("**Embed:** {}\n", result.embed_url));
+        }
+        let description = strip_html(&result.description);
+        if !description.is_empty() {
+            output.push_str(&format!("{}\n", description));
+        }
+        output.push_str("\n---\n\n");
+    }
+
+    output.push_str(&format!(
+        "_Source: DuckDuckGo Videos ({} results)_",
+        results.len()
+    ));
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> VideoResponse {
+        serde_json::from_str(include_str!("../../tests/fixtures/video_results.json")).unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_video_fixture() {
+        let response = fixture();
+        assert_eq!(response.results.len(), 3);
+        assert_eq!(response.results[0].statistics.view_count, Some(1234567));
+        assert_eq!(response.results[2].statistics.view_count, None);
+        assert_eq!(
+            response.results[0].embed_url,
+            "https://www.youtube.com/embed/zF34dRivLOw?autoplay=1"
+        );
+    }
+
+    #[test]
+    fn test_upload_date() {
+        let response = fixture();
+        assert_eq!(response.results[0].upload_date(), Some("2023-04-12"));
+        assert_eq!(response.results[2].upload_date(), None);
+    }
+
+    #[test]
+    fn test_f_param() {
+        assert_eq!(VideoFilters::default().f_param(), ",,,");
+        let filters = VideoFilters {
+            duration: Some(VideoDuration::Short),
+            resolution: Some(VideoResolution::High),
+        };
+        assert_eq!(
+            filters.f_param(),
+            ",videoDefinition:high,videoDuration:short,"
+        );
+    }
+
+    #[test]
+    fn test_format_videos_markdown() {
+        let output = format_videos_markdown("rust tutorial", &fixture().results);
+        assert!(output.contains("### 1. Rust Programming Course for Beginners - Tutorial\n"));
+        assert!(output.contains("**URL:** https://www.youtube.com/watch?v=zF34dRivLOw\n"));
+        assert!(output.contains("**Publisher:** YouTube (freeCodeCamp.org)\n"));
+        assert!(output.contains("**Duration:** 13:59:10\n"));
+        assert!(output.contains("**Uploaded:** 2023-04-12\n"));
+        assert!(output.contains("**Views:** 1234567\n"));
+        assert!(
+            output.contains("**Embed:** https://www.youtube.com/embed/zF34dRivLOw?autoplay=1\n")
+        );
+        assert!(output.contains("Learn the Rust programming language"));
+        assert!(output.contains("**Publisher:** Vimeo\n"));
+        assert!(output.ends_with("_Source: DuckDuckGo Videos (3 results)_"));
+    }
+
+    #[test]
+    fn test_format_videos_markdown_empty() {
+        let output = format_videos_markdown("nothing", &[]);
+        assert!(output.contains("No videos found."));
+    }
+}
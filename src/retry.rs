@@ -3,10 +3,20 @@ use std::time::Duration;
 
 use crate::error::WebSearchError;
 
-pub async fn retry_with_backoff<F, Fut>(max_retries: u32, f: F) -> Result<String, WebSearchError>
+const BLOCKED_BACKOFF_SECS: u64 = 5;
+
+fn backoff(err: &WebSearchError, attempt: u32) -> Duration {
+    let base = match err {
+        WebSearchError::Blocked(_) => BLOCKED_BACKOFF_SECS,
+        _ => 1,
+    };
+    Duration::from_secs(base << attempt)
+}
+
+pub async fn retry_with_backoff<T, F, Fut>(max_retries: u32, f: F) -> Result<T, WebSearchError>
 where
     F: Fn() -> Fut,
-    Fut: Future<Output = Result<String, WebSearchError>>,
+    Fut: Future<Output = Result<T, WebSearchError>>,
 {
     let mut last_err = None;
     for attempt in 0..=max_retries {
@@ -16,8 +26,7 @@ where
                 if !e.is_retryable() || attempt == max_retries {
                     return Err(e);
                 }
-                let backoff = Duration::from_secs(1 << attempt);
-                tokio::time::sleep(backoff).await;
+                tokio::time::sleep(backoff(&e, attempt)).await;
                 last_err = Some(e);
             }
         }
@@ -71,7 +80,7 @@ mod tests {
     async fn test_gives_up_after_max_retries() {
         let call_count = Arc::new(AtomicU32::new(0));
         let count = call_count.clone();
-        let result = retry_with_backoff(2, || {
+        let result: Result<String, _> = retry_with_backoff(2, || {
             let count = count.clone();
             async move {
                 count.fetch_add(1, Ordering::SeqCst);
@@ -84,11 +93,33 @@ mod tests {
         assert_eq!(call_count.load(Ordering::SeqCst), 3);
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn test_blocked_uses_longer_backoff() {
+        let call_count = Arc::new(AtomicU32::new(0));
+        let count = call_count.clone();
+        let start = tokio::time::Instant::now();
+        let result = retry_with_backoff(2, || {
+            let count = count.clone();
+            async move {
+                let n = count.fetch_add(1, Ordering::SeqCst);
+                if n < 2 {
+                    Err(WebSearchError::Blocked("DuckDuckGo".to_string()))
+                } else {
+                    Ok("unblocked".to_string())
+                }
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), "unblocked");
+        // 5s + 10s instead of 1s + 2s
+        assert!(start.elapsed() >= Duration::from_secs(15));
+    }
+
     #[tokio::test]
     async fn test_does_not_retry_non_retryable_error() {
         let call_count = Arc::new(AtomicU32::new(0));
         let count = call_count.clone();
-        let result = retry_with_backoff(3, || {
+        let result: Result<String, _> = retry_with_backoff(3, || {
             let count = count.clone();
             async move {
                 count.fetch_add(1, Ordering::SeqCst);
@@ -104,7 +135,7 @@ mod tests {
     async fn test_zero_retries_only_tries_once() {
         let call_count = Arc::new(AtomicU32::new(0));
         let count = call_count.clone();
-        let result = retry_with_backoff(0, || {
+        let result: Result<String, _> = retry_with_backoff(0, || {
             let count = count.clone();
             async move {
                 count.fetch_add(1, Ordering::SeqCst);
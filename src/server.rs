@@ -8,34 +8,188 @@ use rmcp::schemars;
 use rmcp::{tool, tool_handler, tool_router, ServerHandler};
 use serde::Deserialize;
 
+use crate::backends::brave::BraveBackend;
+use crate::backends::duckduckgo::{
+    DuckDuckGoBackend, DUCKDUCKGO_HTML_BASE_URL, DUCKDUCKGO_LITE_BASE_URL,
+};
+use crate::backends::fusion::search_all;
+use crate::backends::searxng::SearxngBackend;
+use crate::backends::{search_backend, BackendRegistry};
 use crate::cache::TtlCache;
 use crate::config::Config;
+use crate::error::WebSearchError;
+use crate::models::images::{
+    format_images_markdown, ImageColor, ImageFilters, ImageLayout, ImageResult, ImageSize,
+    ImageType,
+};
+use crate::models::instant_answer::Disambiguation;
+use crate::models::search::{
+    normalize_domains, SafeSearch, SearchOptions, SearchOutput, TimeRange,
+};
+use crate::models::suggestions::format_suggestions_markdown;
+use crate::models::videos::{VideoDuration, VideoFilters, VideoResolution};
 use crate::rate_limiter::RateLimiter;
 use crate::retry::retry_with_backoff;
-use crate::tools::instant_answer::execute_instant_answer;
-use crate::tools::web_search::execute_web_search;
+use crate::tools::fetch_url::{
+    fetch_document, format_page_markdown, FetchedPage, DEFAULT_MAX_LENGTH,
+};
+use crate::tools::image_search::{fetch_thumbnails, search_images, MAX_THUMBNAIL_BYTES};
+use crate::tools::instant_answer::{execute_instant_answer, fetch_instant_answer};
+use crate::tools::news_search::execute_news_search;
+use crate::tools::search::format_combined_markdown;
+use crate::tools::search_suggestions::fetch_suggestions;
+use crate::tools::video_search::execute_video_search;
 
-const DUCKDUCKGO_HTML_BASE_URL: &str = "https://html.duckduckgo.com";
 const DUCKDUCKGO_API_BASE_URL: &str = "https://api.duckduckgo.com";
+const DUCKDUCKGO_BASE_URL: &str = "https://duckduckgo.com";
+
+#[derive(Debug, Clone)]
+pub struct DuckDuckGoUrls {
+    pub html: String,
+    pub lite: String,
+    pub api: String,
+    pub web: String,
+}
+
+impl DuckDuckGoUrls {
+    pub fn single_origin(base_url: &str) -> Self {
+        Self {
+            html: base_url.to_string(),
+            lite: base_url.to_string(),
+            api: base_url.to_string(),
+            web: base_url.to_string(),
+        }
+    }
+}
+
+impl Default for DuckDuckGoUrls {
+    fn default() -> Self {
+        Self {
+            html: DUCKDUCKGO_HTML_BASE_URL.to_string(),
+            lite: DUCKDUCKGO_LITE_BASE_URL.to_string(),
+            api: DUCKDUCKGO_API_BASE_URL.to_string(),
+            web: DUCKDUCKGO_BASE_URL.to_string(),
+        }
+    }
+}
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct WebSearchParams {
     pub query: String,
     pub max_results: Option<usize>,
+    /// 1-based result page to start from, up to 10 (default: 1)
+    pub page: Option<usize>,
+    /// DuckDuckGo region code such as `jp-jp` or `us-en` (default: server setting)
+    pub region: Option<String>,
+    /// Safe search level (default: server setting)
+    pub safe_search: Option<SafeSearch>,
+    /// Only return results published within this period
+    pub time_range: Option<TimeRange>,
+    /// Only return results from these domains (subdomains included)
+    pub include_domains: Option<Vec<String>>,
+    /// Drop results from these domains (subdomains included)
+    pub exclude_domains: Option<Vec<String>>,
+    /// Query every configured backend concurrently and merge the results (default: false)
+    pub fan_out: Option<bool>,
+    /// Show sponsored results in a separate section instead of dropping them (default: false)
+    pub include_ads: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SearchParams {
+    pub query: String,
+    pub max_results: Option<usize>,
+    /// DuckDuckGo region code such as `jp-jp` or `us-en` (default: server setting)
+    pub region: Option<String>,
+    /// Safe search level (default: server setting)
+    pub safe_search: Option<SafeSearch>,
+    /// Only return web results published within this period
+    pub time_range: Option<TimeRange>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct NewsSearchParams {
+    pub query: String,
+    pub max_results: Option<usize>,
+    /// DuckDuckGo region code such as `jp-jp` or `us-en` (default: server setting)
+    pub region: Option<String>,
+    /// Safe search level (default: server setting)
+    pub safe_search: Option<SafeSearch>,
+    /// Only return articles published within this period
+    pub time_range: Option<TimeRange>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ImageSearchParams {
+    pub query: String,
+    pub max_results: Option<usize>,
+    /// DuckDuckGo region code such as `jp-jp` or `us-en` (default: server setting)
+    pub region: Option<String>,
+    /// Safe search level (default: server setting)
+    pub safe_search: Option<SafeSearch>,
+    /// Only return images of this size
+    pub size: Option<ImageSize>,
+    /// Only return images with this dominant color (`color` excludes black-and-white images)
+    pub color: Option<ImageColor>,
+    /// Only return images of this kind
+    pub image_type: Option<ImageType>,
+    /// Only return images with this aspect ratio
+    pub layout: Option<ImageLayout>,
+    /// Attach thumbnails as base64 image content so multimodal clients can view them (default: false)
+    pub include_thumbnails: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct VideoSearchParams {
+    pub query: String,
+    pub max_results: Option<usize>,
+    /// DuckDuckGo region code such as `jp-jp` or `us-en` (default: server setting)
+    pub region: Option<String>,
+    /// Safe search level (default: server setting)
+    pub safe_search: Option<SafeSearch>,
+    /// Only return videos of this length (`short` < 4 min, `medium` 4-20 min, `long` > 20 min)
+    pub duration: Option<VideoDuration>,
+    /// Only return videos of this resolution
+    pub resolution: Option<VideoResolution>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SearchSuggestionsParams {
+    /// Partial query to complete
+    pub query: String,
+    pub max_results: Option<usize>,
+    /// DuckDuckGo region code such as `jp-jp` or `us-en` (default: server setting)
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct FetchUrlParams {
+    /// http or https URL of the page to read
+    pub url: String,
+    /// Character offset into the converted Markdown to start reading from (default: 0)
+    pub start_index: Option<usize>,
+    /// Maximum number of characters of Markdown to return (default: 20000)
+    #[serde(alias = "max_chars")]
+    pub max_length: Option<usize>,
+    /// Keep only the main content, dropping menus, cookie banners and footers (default: false)
+    pub extract_main_content: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct InstantAnswerParams {
     pub query: String,
+    /// How to handle ambiguous queries: `skip` returns DuckDuckGo's best guess, `list` returns the candidate meanings (default: skip)
+    pub disambiguation: Option<Disambiguation>,
 }
 
 #[derive(Debug, Clone)]
 pub struct Server {
     client: reqwest::Client,
     config: Config,
-    html_base_url: String,
-    api_base_url: String,
+    urls: DuckDuckGoUrls,
+    backends: BackendRegistry,
     cache: Arc<TtlCache>,
+    suggestion_cache: Arc<TtlCache>,
     rate_limiter: Arc<RateLimiter>,
     tool_router: ToolRouter<Self>,
 }
@@ -43,68 +197,283 @@ pub struct Server {
 #[tool_router]
 impl Server {
     pub fn new(client: reqwest::Client, config: Config) -> Self {
-        let cache = Arc::new(TtlCache::new(Duration::from_secs(config.cache_ttl_secs)));
+        Self::with_base_urls(client, config, DuckDuckGoUrls::default())
+    }
+
+    pub fn with_base_urls(client: reqwest::Client, config: Config, urls: DuckDuckGoUrls) -> Self {
         let rate_limiter = Arc::new(RateLimiter::new(Duration::from_millis(
             config.rate_limit_ms,
         )));
-        Self {
-            client,
-            config,
-            html_base_url: DUCKDUCKGO_HTML_BASE_URL.to_string(),
-            api_base_url: DUCKDUCKGO_API_BASE_URL.to_string(),
-            cache,
-            rate_limiter,
-            tool_router: Self::tool_router(),
+        let mut backends = BackendRegistry::new();
+        backends.register(Arc::new(DuckDuckGoBackend::new(
+            client.clone(),
+            urls.html.clone(),
+            urls.lite.clone(),
+            rate_limiter.clone(),
+            config.timeout_secs,
+        )));
+        if let Some(searxng_url) = &config.searxng_url {
+            backends.register(Arc::new(SearxngBackend::new(
+                client.clone(),
+                searxng_url.clone(),
+                Arc::new(RateLimiter::new(Duration::from_millis(
+                    config.rate_limit_ms,
+                ))),
+                config.timeout_secs,
+            )));
+        }
+        if let Some(api_key) = &config.brave_api_key {
+            backends.register(Arc::new(BraveBackend::new(
+                client.clone(),
+                config.brave_base_url.clone(),
+                api_key.clone(),
+                Arc::new(RateLimiter::new(Duration::from_millis(
+                    config.brave_rate_limit_ms,
+                ))),
+                config.timeout_secs,
+            )));
         }
+        Self::build(client, config, urls, backends, rate_limiter)
     }
 
-    pub fn with_base_urls(
+    pub fn with_backends(
         client: reqwest::Client,
         config: Config,
-        html_base_url: String,
-        api_base_url: String,
+        urls: DuckDuckGoUrls,
+        backends: BackendRegistry,
     ) -> Self {
-        let cache = Arc::new(TtlCache::new(Duration::from_secs(config.cache_ttl_secs)));
         let rate_limiter = Arc::new(RateLimiter::new(Duration::from_millis(
             config.rate_limit_ms,
         )));
+        Self::build(client, config, urls, backends, rate_limiter)
+    }
+
+    fn build(
+        client: reqwest::Client,
+        config: Config,
+        urls: DuckDuckGoUrls,
+        backends: BackendRegistry,
+        rate_limiter: Arc<RateLimiter>,
+    ) -> Self {
+        let cache = Arc::new(TtlCache::new(Duration::from_secs(config.cache_ttl_secs)));
+        let suggestion_cache = Arc::new(TtlCache::new(Duration::from_secs(
+            config.suggestion_cache_ttl_secs,
+        )));
         Self {
             client,
             config,
-            html_base_url,
-            api_base_url,
+            urls,
+            backends,
             cache,
+            suggestion_cache,
             rate_limiter,
             tool_router: Self::tool_router(),
         }
     }
 
-    #[tool(description = "Search the web using DuckDuckGo and return results as Markdown")]
+    fn resolve_region(&self, region: Option<&str>) -> Option<String> {
+        region
+            .map(|r| r.trim().to_ascii_lowercase())
+            .filter(|r| !r.is_empty())
+            .or_else(|| self.config.region.clone())
+    }
+
+    async fn cached_search_output(&self, cache_key: &str) -> Option<SearchOutput> {
+        self.cache
+            .get(cache_key)
+            .await
+            .and_then(|json| serde_json::from_str(&json).ok())
+    }
+
+    async fn cache_search_output(&self, cache_key: String, output: &SearchOutput) {
+        if let Ok(json) = serde_json::to_string(output) {
+            self.cache.set(cache_key, json).await;
+        }
+    }
+
+    #[tool(
+        description = "Search the web and return results as Markdown, with the same results as JSON in structured content"
+    )]
     async fn web_search(
         &self,
         params: Parameters<WebSearchParams>,
     ) -> Result<CallToolResult, rmcp::ErrorData> {
-        let max_results = params.0.max_results.unwrap_or(self.config.max_results);
-        let cache_key = format!("web_search:{}:{}", params.0.query, max_results);
+        let options = SearchOptions {
+            max_results: params.0.max_results.unwrap_or(self.config.max_results),
+            page: params.0.page.unwrap_or(1).max(1),
+            region: self.resolve_region(params.0.region.as_deref()),
+            safe_search: params.0.safe_search.unwrap_or(self.config.safe_search),
+            time_range: params.0.time_range,
+            include_domains: normalize_domains(
+                params.0.include_domains.as_deref().unwrap_or_default(),
+            ),
+            exclude_domains: normalize_domains(
+                params.0.exclude_domains.as_deref().unwrap_or_default(),
+            ),
+            include_ads: params.0.include_ads.unwrap_or(false),
+        };
+
+        let query = params.0.query.clone();
+        let max_retries = self.config.max_retries;
+
+        if params.0.fan_out.unwrap_or(false) && self.backends.len() > 1 {
+            let backends = self.backends.all();
+            let names: Vec<&str> = backends.iter().map(|b| b.name()).collect();
+            let cache_key = format!(
+                "web_search:{}:{}",
+                names.join("+"),
+                options.cache_key(&query)
+            );
+
+            if let Some(cached) = self.cached_search_output(&cache_key).await {
+                return Ok(cached.to_tool_result());
+            }
+
+            return Ok(
+                match search_all(&backends, &query, &options, max_retries).await {
+                    Ok(fused) => {
+                        let output = SearchOutput {
+                            query,
+                            source: fused.attribution,
+                            response: fused.response,
+                        };
+                        if output.response.warnings.is_empty() {
+                            self.cache_search_output(cache_key, &output).await;
+                        }
+                        output.to_tool_result()
+                    }
+                    Err(e) => e.to_tool_result(),
+                },
+            );
+        }
+
+        let Some(backend) = self.backends.get(&self.config.backend) else {
+            return Ok(WebSearchError::UnknownBackend(self.config.backend.clone()).to_tool_result());
+        };
+
+        let cache_key = format!(
+            "web_search:{}:{}",
+            backend.name(),
+            options.cache_key(&query)
+        );
+
+        if let Some(cached) = self.cached_search_output(&cache_key).await {
+            return Ok(cached.to_tool_result());
+        }
+
+        let result = retry_with_backoff(max_retries, || {
+            let backend = backend.clone();
+            let query = query.clone();
+            let options = options.clone();
+            async move { search_backend(backend.as_ref(), &query, &options).await }
+        })
+        .await;
+
+        Ok(match result {
+            Ok(response) => {
+                let output = SearchOutput {
+                    query,
+                    source: backend.attribution().to_string(),
+                    response,
+                };
+                self.cache_search_output(cache_key, &output).await;
+                output.to_tool_result()
+            }
+            Err(e) => e.to_tool_result(),
+        })
+    }
+
+    #[tool(
+        description = "Search the web and return a direct answer (when available) above the organic results as Markdown"
+    )]
+    async fn search(
+        &self,
+        params: Parameters<SearchParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let options = SearchOptions {
+            max_results: params.0.max_results.unwrap_or(self.config.max_results),
+            region: self.resolve_region(params.0.region.as_deref()),
+            safe_search: params.0.safe_search.unwrap_or(self.config.safe_search),
+            time_range: params.0.time_range,
+            ..Default::default()
+        };
+        let query = params.0.query.clone();
+        let Some(backend) = self.backends.get(&self.config.backend) else {
+            return Ok(WebSearchError::UnknownBackend(self.config.backend.clone()).to_tool_result());
+        };
+        let cache_key = format!("search:{}:{}", backend.name(), options.cache_key(&query));
 
         if let Some(cached) = self.cache.get(&cache_key).await {
             return Ok(CallToolResult::success(vec![Content::text(cached)]));
         }
 
-        self.rate_limiter.acquire().await;
+        let answer = retry_with_backoff(self.config.max_retries, || {
+            let query = query.clone();
+            async move {
+                self.rate_limiter.acquire().await;
+                fetch_instant_answer(
+                    &self.client,
+                    &self.urls.api,
+                    &query,
+                    Disambiguation::Skip,
+                    self.config.timeout_secs,
+                )
+                .await
+            }
+        });
+        let web = retry_with_backoff(self.config.max_retries, || {
+            let backend = backend.clone();
+            let query = query.clone();
+            let options = options.clone();
+            async move { search_backend(backend.as_ref(), &query, &options).await }
+        });
+        let (answer, web) = tokio::join!(answer, web);
 
-        let client = self.client.clone();
-        let html_base_url = self.html_base_url.clone();
+        Ok(
+            match format_combined_markdown(&query, answer, web, backend.attribution()) {
+                Ok(combined) => {
+                    if combined.complete {
+                        self.cache.set(cache_key, combined.markdown.clone()).await;
+                    }
+                    CallToolResult::success(vec![Content::text(combined.markdown)])
+                }
+                Err(e) => e.to_tool_result(),
+            },
+        )
+    }
+
+    #[tool(description = "Search recent news articles and return them newest first as Markdown")]
+    async fn news_search(
+        &self,
+        params: Parameters<NewsSearchParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let options = SearchOptions {
+            max_results: params.0.max_results.unwrap_or(self.config.max_results),
+            region: self.resolve_region(params.0.region.as_deref()),
+            safe_search: params.0.safe_search.unwrap_or(self.config.safe_search),
+            time_range: params.0.time_range,
+            ..Default::default()
+        };
         let query = params.0.query.clone();
-        let timeout_secs = self.config.timeout_secs;
-        let max_retries = self.config.max_retries;
+        let cache_key = format!("news_search:{}", options.cache_key(&query));
 
-        let result = retry_with_backoff(max_retries, || {
-            let client = client.clone();
-            let html_base_url = html_base_url.clone();
+        if let Some(cached) = self.cache.get(&cache_key).await {
+            return Ok(CallToolResult::success(vec![Content::text(cached)]));
+        }
+
+        let result = retry_with_backoff(self.config.max_retries, || {
             let query = query.clone();
+            let options = options.clone();
             async move {
-                execute_web_search(&client, &html_base_url, &query, max_results, timeout_secs).await
+                execute_news_search(
+                    &self.client,
+                    &self.rate_limiter,
+                    &self.urls.web,
+                    &query,
+                    &options,
+                    self.config.timeout_secs,
+                )
+                .await
             }
         })
         .await;
@@ -118,12 +487,254 @@ impl Server {
         })
     }
 
+    #[tool(
+        description = "Search images and return their URLs, thumbnails, dimensions and source pages"
+    )]
+    async fn image_search(
+        &self,
+        params: Parameters<ImageSearchParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let options = SearchOptions {
+            max_results: params.0.max_results.unwrap_or(self.config.max_results),
+            region: self.resolve_region(params.0.region.as_deref()),
+            safe_search: params.0.safe_search.unwrap_or(self.config.safe_search),
+            ..Default::default()
+        };
+        let filters = ImageFilters {
+            size: params.0.size,
+            color: params.0.color,
+            image_type: params.0.image_type,
+            layout: params.0.layout,
+        };
+        let query = params.0.query.clone();
+        // Results are cached as JSON so thumbnails can be rebuilt from a cache hit.
+        let cache_key = format!(
+            "image_search:{}:{}",
+            options.cache_key(&query),
+            filters.f_param()
+        );
+
+        let cached = self
+            .cache
+            .get(&cache_key)
+            .await
+            .and_then(|json| serde_json::from_str::<Vec<ImageResult>>(&json).ok());
+        let results = match cached {
+            Some(results) => results,
+            None => {
+                let result = retry_with_backoff(self.config.max_retries, || {
+                    let query = query.clone();
+                    let options = options.clone();
+                    async move {
+                        search_images(
+                            &self.client,
+                            &self.rate_limiter,
+                            &self.urls.web,
+                            &query,
+                            &options,
+                            &filters,
+                            self.config.timeout_secs,
+                        )
+                        .await
+                    }
+                })
+                .await;
+                match result {
+                    Ok(results) => {
+                        if let Ok(json) = serde_json::to_string(&results) {
+                            self.cache.set(cache_key, json).await;
+                        }
+                        results
+                    }
+                    Err(e) => return Ok(e.to_tool_result()),
+                }
+            }
+        };
+
+        let mut content = vec![Content::text(format_images_markdown(&query, &results))];
+        if params.0.include_thumbnails.unwrap_or(false) {
+            let thumbnails = fetch_thumbnails(
+                &self.client,
+                &results,
+                MAX_THUMBNAIL_BYTES,
+                self.config.timeout_secs,
+            )
+            .await;
+            for (index, thumbnail) in thumbnails.into_iter().enumerate() {
+                if let Some(thumbnail) = thumbnail {
+                    content.push(Content::text(format!("Thumbnail for result {}", index + 1)));
+                    content.push(Content::image(thumbnail.data, thumbnail.mime_type));
+                }
+            }
+        }
+        Ok(CallToolResult::success(content))
+    }
+
+    #[tool(
+        description = "Search videos and return their duration, publisher, upload date, view count and embed URL"
+    )]
+    async fn video_search(
+        &self,
+        params: Parameters<VideoSearchParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let options = SearchOptions {
+            max_results: params.0.max_results.unwrap_or(self.config.max_results),
+            region: self.resolve_region(params.0.region.as_deref()),
+            safe_search: params.0.safe_search.unwrap_or(self.config.safe_search),
+            ..Default::default()
+        };
+        let filters = VideoFilters {
+            duration: params.0.duration,
+            resolution: params.0.resolution,
+        };
+        let query = params.0.query.clone();
+        let cache_key = format!(
+            "video_search:{}:{}",
+            options.cache_key(&query),
+            filters.f_param()
+        );
+
+        if let Some(cached) = self.cache.get(&cache_key).await {
+            return Ok(CallToolResult::success(vec![Content::text(cached)]));
+        }
+
+        let result = retry_with_backoff(self.config.max_retries, || {
+            let query = query.clone();
+            let options = options.clone();
+            async move {
+                execute_video_search(
+                    &self.client,
+                    &self.rate_limiter,
+                    &self.urls.web,
+                    &query,
+                    &options,
+                    &filters,
+                    self.config.timeout_secs,
+                )
+                .await
+            }
+        })
+        .await;
+
+        Ok(match result {
+            Ok(markdown) => {
+                self.cache.set(cache_key, markdown.clone()).await;
+                CallToolResult::success(vec![Content::text(markdown)])
+            }
+            Err(e) => e.to_tool_result(),
+        })
+    }
+
+    #[tool(
+        description = "Suggest ranked query completions for a prefix, useful for refining a query before calling web_search"
+    )]
+    async fn search_suggestions(
+        &self,
+        params: Parameters<SearchSuggestionsParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let query = params.0.query.clone();
+        let region = self.resolve_region(params.0.region.as_deref());
+        let max_results = params.0.max_results.unwrap_or(self.config.max_results);
+        let cache_key = format!(
+            "search_suggestions:{}:{}:{}",
+            region.as_deref().unwrap_or_default(),
+            max_results,
+            query
+        );
+
+        if let Some(cached) = self.suggestion_cache.get(&cache_key).await {
+            return Ok(CallToolResult::success(vec![Content::text(cached)]));
+        }
+
+        let result = retry_with_backoff(self.config.max_retries, || {
+            let query = query.clone();
+            let region = region.clone();
+            async move {
+                fetch_suggestions(
+                    &self.client,
+                    &self.rate_limiter,
+                    &self.urls.web,
+                    &query,
+                    region.as_deref(),
+                    max_results,
+                    self.config.timeout_secs,
+                )
+                .await
+            }
+        })
+        .await;
+
+        Ok(match result {
+            Ok(suggestions) => {
+                let markdown = format_suggestions_markdown(&query, &suggestions);
+                self.suggestion_cache.set(cache_key, markdown.clone()).await;
+                CallToolResult::success(vec![Content::text(markdown)])
+            }
+            Err(e) => e.to_tool_result(),
+        })
+    }
+
+    #[tool(
+        description = "Fetch a web page and return its content as Markdown, without scripts, styles and navigation"
+    )]
+    async fn fetch_url(
+        &self,
+        params: Parameters<FetchUrlParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let start_index = params.0.start_index.unwrap_or(0);
+        let max_length = params.0.max_length.unwrap_or(DEFAULT_MAX_LENGTH);
+        let extract_main_content = params.0.extract_main_content.unwrap_or(false);
+        let url = params.0.url.trim().to_string();
+        // The whole converted page is cached so follow-up slices reuse one download.
+        let mode = if extract_main_content { "main" } else { "full" };
+        let cache_key = format!("fetch_url:{}:{}", mode, url);
+
+        let cached = self
+            .cache
+            .get(&cache_key)
+            .await
+            .and_then(|json| serde_json::from_str::<FetchedPage>(&json).ok());
+        let page = match cached {
+            Some(page) => page,
+            None => {
+                let result = retry_with_backoff(self.config.max_retries, || async {
+                    fetch_document(
+                        &self.client,
+                        &url,
+                        extract_main_content,
+                        self.config.timeout_secs,
+                    )
+                    .await
+                })
+                .await;
+                match result {
+                    Ok(page) => {
+                        if let Ok(json) = serde_json::to_string(&page) {
+                            self.cache.set(cache_key, json).await;
+                        }
+                        page
+                    }
+                    Err(e) => return Ok(e.to_tool_result()),
+                }
+            }
+        };
+
+        Ok(CallToolResult::success(vec![Content::text(
+            format_page_markdown(&page, start_index, max_length),
+        )]))
+    }
+
     #[tool(description = "Get an instant answer from DuckDuckGo for a given query")]
     async fn instant_answer(
         &self,
         params: Parameters<InstantAnswerParams>,
     ) -> Result<CallToolResult, rmcp::ErrorData> {
-        let cache_key = format!("instant_answer:{}", params.0.query);
+        let disambiguation = params.0.disambiguation.unwrap_or_default();
+        let cache_key = format!(
+            "instant_answer:{}:{}",
+            disambiguation.as_str(),
+            params.0.query
+        );
 
         if let Some(cached) = self.cache.get(&cache_key).await {
             return Ok(CallToolResult::success(vec![Content::text(cached)]));
@@ -132,7 +743,7 @@ impl Server {
         self.rate_limiter.acquire().await;
 
         let client = self.client.clone();
-        let api_base_url = self.api_base_url.clone();
+        let api_base_url = self.urls.api.clone();
         let query = params.0.query.clone();
         let timeout_secs = self.config.timeout_secs;
         let max_retries = self.config.max_retries;
@@ -141,7 +752,10 @@ impl Server {
             let client = client.clone();
             let api_base_url = api_base_url.clone();
             let query = query.clone();
-            async move { execute_instant_answer(&client, &api_base_url, &query, timeout_secs).await }
+            async move {
+                execute_instant_answer(&client, &api_base_url, &query, disambiguation, timeout_secs)
+                    .await
+            }
         })
         .await;
 
@@ -181,17 +795,25 @@ mod tests {
             timeout_secs: 10,
             user_agent: "test-agent".to_string(),
             cache_ttl_secs: 300,
+            suggestion_cache_ttl_secs: 60,
             rate_limit_ms: 1000,
             max_retries: 3,
+            region: None,
+            safe_search: SafeSearch::Moderate,
+            backend: "duckduckgo".to_string(),
+            searxng_url: None,
+            brave_api_key: None,
+            brave_base_url: "https://api.search.brave.com".to_string(),
+            brave_rate_limit_ms: 1000,
         };
         let client = build_http_client(&config).unwrap();
         Server::new(client, config)
     }
 
     #[test]
-    fn test_tool_router_has_two_tools() {
+    fn test_tool_router_lists_all_tools() {
         let server = create_test_server();
-        assert_eq!(server.tool_router.list_all().len(), 2);
+        assert_eq!(server.tool_router.list_all().len(), 8);
     }
 
     #[test]
@@ -201,6 +823,93 @@ mod tests {
         let names: Vec<String> = tools.iter().map(|t| t.name.to_string()).collect();
         assert!(names.contains(&"web_search".to_string()));
         assert!(names.contains(&"instant_answer".to_string()));
+        assert!(names.contains(&"news_search".to_string()));
+        assert!(names.contains(&"image_search".to_string()));
+        assert!(names.contains(&"video_search".to_string()));
+        assert!(names.contains(&"search_suggestions".to_string()));
+        assert!(names.contains(&"search".to_string()));
+        assert!(names.contains(&"fetch_url".to_string()));
+    }
+
+    #[test]
+    fn test_image_search_schema_exposes_filters() {
+        let server = create_test_server();
+        let tool = server
+            .tool_router
+            .list_all()
+            .into_iter()
+            .find(|t| t.name == "image_search")
+            .unwrap();
+        let schema = serde_json::to_string(&tool.input_schema).unwrap();
+        for field in [
+            "size",
+            "color",
+            "image_type",
+            "layout",
+            "include_thumbnails",
+        ] {
+            assert!(schema.contains(&format!("\"{field}\"")));
+        }
+        for value in ["wallpaper", "monochrome", "transparent", "wide"] {
+            assert!(schema.contains(&format!("\"{value}\"")));
+        }
+    }
+
+    #[test]
+    fn test_web_search_schema_exposes_region_and_safe_search() {
+        let server = create_test_server();
+        let tool = server
+            .tool_router
+            .list_all()
+            .into_iter()
+            .find(|t| t.name == "web_search")
+            .unwrap();
+        let schema = serde_json::to_string(&tool.input_schema).unwrap();
+        assert!(schema.contains("\"region\""));
+        assert!(schema.contains("\"safe_search\""));
+        assert!(schema.contains("\"strict\""));
+        assert!(schema.contains("\"moderate\""));
+        assert!(schema.contains("\"off\""));
+    }
+
+    #[test]
+    fn test_web_search_schema_exposes_time_range_enum() {
+        let server = create_test_server();
+        let tool = server
+            .tool_router
+            .list_all()
+            .into_iter()
+            .find(|t| t.name == "web_search")
+            .unwrap();
+        let schema = serde_json::to_string(&tool.input_schema).unwrap();
+        assert!(schema.contains("\"time_range\""));
+        for value in ["day", "week", "month", "year"] {
+            assert!(schema.contains(&format!("\"{value}\"")));
+        }
+    }
+
+    #[test]
+    fn test_default_server_registers_duckduckgo_backend() {
+        let server = create_test_server();
+        assert_eq!(server.backends.names(), vec!["duckduckgo"]);
+    }
+
+    #[test]
+    fn test_searxng_backend_registered_when_configured() {
+        let mut config = create_test_server().config;
+        config.searxng_url = Some("http://127.0.0.1:8888".to_string());
+        let client = build_http_client(&config).unwrap();
+        let server = Server::new(client, config);
+        assert_eq!(server.backends.names(), vec!["duckduckgo", "searxng"]);
+    }
+
+    #[test]
+    fn test_brave_backend_registered_when_api_key_configured() {
+        let mut config = create_test_server().config;
+        config.brave_api_key = Some("test-key".to_string());
+        let client = build_http_client(&config).unwrap();
+        let server = Server::new(client, config);
+        assert_eq!(server.backends.names(), vec!["duckduckgo", "brave"]);
     }
 
     #[test]
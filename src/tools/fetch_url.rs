@@ -0,0 +1,215 @@
+use reqwest::header::{ACCEPT, CONTENT_TYPE};
+use reqwest::Url;
+use scraper::Html;
+use serde::{Deserialize, Serialize};
+
+use crate::error::WebSearchError;
+use crate::extract::main_content;
+use crate::http_client::send;
+use crate::markdown::{element_to_markdown, page_title};
+
+pub const DEFAULT_MAX_LENGTH: usize = 20_000;
+
+/// Upper bound on the downloaded body, enforced while streaming.
+pub const MAX_DOCUMENT_BYTES: usize = 10 * 1024 * 1024;
+
+const ACCEPT_HEADER: &str = "text/html,application/xhtml+xml,text/plain;q=0.9,*/*;q=0.1";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FetchedPage {
+    /// Final URL after redirects
+    pub url: String,
+    pub title: Option<String>,
+    pub markdown: String,
+}
+
+pub fn parse_fetch_url(url: &str) -> Result<Url, WebSearchError> {
+    let parsed = Url::parse(url.trim()).map_err(|_| WebSearchError::InvalidUrl(url.to_string()))?;
+    match parsed.scheme() {
+        "http" | "https" if parsed.host_str().is_some() => Ok(parsed),
+        _ => Err(WebSearchError::InvalidUrl(url.to_string())),
+    }
+}
+
+pub async fn fetch_document(
+    client: &reqwest::Client,
+    url: &str,
+    extract_main_content: bool,
+    timeout_secs: u64,
+) -> Result<FetchedPage, WebSearchError> {
+    let url = parse_fetch_url(url)?;
+    let mut response = send(client.get(url).header(ACCEPT, ACCEPT_HEADER), timeout_secs).await?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(WebSearchError::PageUnavailable(status.as_u16()));
+    }
+
+    let final_url = response.url().clone();
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(|value| value.trim().to_ascii_lowercase())
+        .unwrap_or_default();
+    let is_html = matches!(
+        content_type.as_str(),
+        "" | "text/html" | "application/xhtml+xml"
+    );
+    let is_text = matches!(content_type.as_str(), "text/plain" | "text/markdown");
+    if !is_html && !is_text {
+        return Err(WebSearchError::UnsupportedContentType(content_type));
+    }
+
+    if response
+        .content_length()
+        .is_some_and(|length| length > MAX_DOCUMENT_BYTES as u64)
+    {
+        return Err(WebSearchError::DocumentTooLarge(MAX_DOCUMENT_BYTES));
+    }
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if bytes.len() + chunk.len() > MAX_DOCUMENT_BYTES {
+            return Err(WebSearchError::DocumentTooLarge(MAX_DOCUMENT_BYTES));
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    let body = String::from_utf8_lossy(&bytes);
+
+    if is_text {
+        return Ok(FetchedPage {
+            url: final_url.to_string(),
+            title: None,
+            markdown: body.trim().to_string(),
+        });
+    }
+
+    let document = Html::parse_document(&body);
+    let root = extract_main_content
+        .then(|| main_content(&document))
+        .flatten()
+        .unwrap_or_else(|| document.root_element());
+    Ok(FetchedPage {
+        url: final_url.to_string(),
+        title: page_title(&document),
+        markdown: element_to_markdown(root, Some(&final_url)),
+    })
+}
+
+/// Renders the characters `[start_index, start_index + max_length)` of the page,
+/// with a footer pointing at the next `start_index` when more content remains.
+pub fn format_page_markdown(page: &FetchedPage, start_index: usize, max_length: usize) -> String {
+    let mut output = format!(
+        "## {}\n\n**URL:** {}\n\n",
+        page.title.as_deref().unwrap_or(&page.url),
+        page.url
+    );
+
+    if page.markdown.is_empty() {
+        output.push_str("_No readable content found._");
+        return output;
+    }
+
+    let total = page.markdown.chars().count();
+    if start_index >= total {
+        output.push_str(&format!(
+            "_start_index {} is past the end of the document ({} characters)._",
+            start_index, total
+        ));
+        return output;
+    }
+
+    let end = start_index.saturating_add(max_length).min(total);
+    if start_index > 0 || end < total {
+        output.push_str(&format!(
+            "**Characters:** {}-{} of {}\n\n",
+            start_index, end, total
+        ));
+    }
+    let slice: String = page
+        .markdown
+        .chars()
+        .skip(start_index)
+        .take(end - start_index)
+        .collect();
+    output.push_str(slice.trim_end());
+    if end < total {
+        output.push_str(&format!(
+            "\n\n_Content truncated, continue at start_index={} ({} characters remaining)._",
+            end,
+            total - end
+        ));
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(markdown: &str) -> FetchedPage {
+        FetchedPage {
+            url: "https://example.com/".to_string(),
+            title: Some("Example".to_string()),
+            markdown: markdown.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_fetch_url_accepts_http_and_https() {
+        assert!(parse_fetch_url("https://example.com/a").is_ok());
+        assert!(parse_fetch_url("http://example.com").is_ok());
+    }
+
+    #[test]
+    fn test_parse_fetch_url_rejects_other_schemes() {
+        for url in ["ftp://example.com", "file:///etc/passwd", "example.com", ""] {
+            assert!(matches!(
+                parse_fetch_url(url),
+                Err(WebSearchError::InvalidUrl(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_format_page_markdown() {
+        let output = format_page_markdown(&page("# Hello\n\nWorld"), 0, 100);
+        assert_eq!(
+            output,
+            "## Example\n\n**URL:** https://example.com/\n\n# Hello\n\nWorld"
+        );
+    }
+
+    #[test]
+    fn test_format_page_markdown_truncates_on_char_boundary() {
+        let output = format_page_markdown(&page("日本語のテキスト"), 0, 3);
+        assert!(output.contains("**Characters:** 0-3 of 8\n\n日本語\n\n"));
+        assert!(output
+            .ends_with("_Content truncated, continue at start_index=3 (5 characters remaining)._"));
+    }
+
+    #[test]
+    fn test_format_page_markdown_slices_from_start_index() {
+        let output = format_page_markdown(&page("日本語のテキスト"), 3, 3);
+        assert!(output.contains("**Characters:** 3-6 of 8\n\nのテキ\n\n"));
+        assert!(output.ends_with("continue at start_index=6 (2 characters remaining)._"));
+
+        let last = format_page_markdown(&page("日本語のテキスト"), 6, 100);
+        assert!(last.ends_with("**Characters:** 6-8 of 8\n\nスト"));
+    }
+
+    #[test]
+    fn test_format_page_markdown_start_index_past_end() {
+        let output = format_page_markdown(&page("short"), 5, 100);
+        assert!(output.ends_with("_start_index 5 is past the end of the document (5 characters)._"));
+    }
+
+    #[test]
+    fn test_format_page_markdown_without_title_or_content() {
+        let mut page = page("");
+        page.title = None;
+        let output = format_page_markdown(&page, 0, 100);
+        assert!(output.starts_with("## https://example.com/\n"));
+        assert!(output.ends_with("_No readable content found._"));
+    }
+}
@@ -0,0 +1,125 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use tokio::task::JoinSet;
+
+use crate::error::WebSearchError;
+use crate::http_client::{fetch_text, send};
+use crate::models::images::{ImageFilters, ImageResponse, ImageResult};
+use crate::models::search::SearchOptions;
+use crate::rate_limiter::RateLimiter;
+use crate::tools::vqd::{media_safe_search, vqd_params};
+
+pub const MAX_THUMBNAIL_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Thumbnail {
+    pub data: String,
+    pub mime_type: String,
+}
+
+pub async fn search_images(
+    client: &reqwest::Client,
+    rate_limiter: &RateLimiter,
+    base_url: &str,
+    query: &str,
+    options: &SearchOptions,
+    filters: &ImageFilters,
+    timeout_secs: u64,
+) -> Result<Vec<ImageResult>, WebSearchError> {
+    let mut params = vqd_params(
+        client,
+        rate_limiter,
+        base_url,
+        query,
+        options,
+        media_safe_search(options.safe_search),
+        timeout_secs,
+    )
+    .await?;
+    params.push(("f", filters.f_param()));
+
+    rate_limiter.acquire().await;
+    let request = client.get(format!("{}/i.js", base_url)).query(&params);
+    let body = fetch_text(request, timeout_secs).await?;
+    let response: ImageResponse = serde_json::from_str(&body)?;
+
+    let mut results = response.results;
+    results.truncate(options.max_results);
+    Ok(results)
+}
+
+pub async fn fetch_thumbnail(
+    client: &reqwest::Client,
+    url: &str,
+    max_bytes: usize,
+    timeout_secs: u64,
+) -> Result<Option<Thumbnail>, WebSearchError> {
+    let mut response = send(client.get(url), timeout_secs)
+        .await?
+        .error_for_status()?;
+    let mime_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| {
+            value
+                .split(';')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_string()
+        })
+        .unwrap_or_default();
+    if !mime_type.starts_with("image/") {
+        return Ok(None);
+    }
+    if response
+        .content_length()
+        .is_some_and(|length| length > max_bytes as u64)
+    {
+        return Ok(None);
+    }
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if bytes.len() + chunk.len() > max_bytes {
+            return Ok(None);
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    Ok(Some(Thumbnail {
+        data: STANDARD.encode(&bytes),
+        mime_type,
+    }))
+}
+
+pub async fn fetch_thumbnails(
+    client: &reqwest::Client,
+    results: &[ImageResult],
+    max_bytes: usize,
+    timeout_secs: u64,
+) -> Vec<Option<Thumbnail>> {
+    let mut tasks = JoinSet::new();
+    for (index, result) in results.iter().enumerate() {
+        if result.thumbnail.is_empty() {
+            continue;
+        }
+        let client = client.clone();
+        let url = result.thumbnail.clone();
+        tasks.spawn(async move {
+            let thumbnail = fetch_thumbnail(&client, &url, max_bytes, timeout_secs).await;
+            (index, url, thumbnail)
+        });
+    }
+
+    let mut thumbnails = vec![None; results.len()];
+    while let Some(joined) = tasks.join_next().await {
+        let Ok((index, url, thumbnail)) = joined else {
+            continue;
+        };
+        match thumbnail {
+            Ok(thumbnail) => thumbnails[index] = thumbnail,
+            Err(e) => tracing::warn!(url = %url, error = %e, "Failed to fetch thumbnail"),
+        }
+    }
+    thumbnails
+}
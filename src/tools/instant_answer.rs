@@ -1,27 +1,178 @@
 use crate::error::WebSearchError;
-use crate::models::instant_answer::{InstantAnswerResponse, RelatedTopic};
+use crate::models::instant_answer::{
+    Disambiguation, InstantAnswerInfobox, InstantAnswerResponse, RelatedTopic, ResultItem,
+};
+
+fn escape_table_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+fn format_infobox(infobox: &InstantAnswerInfobox) -> String {
+    let rows: Vec<String> = infobox
+        .content
+        .iter()
+        .filter_map(|entry| {
+            let value = entry.display_value()?;
+            Some(format!(
+                "| {} | {} |\n",
+                escape_table_cell(&entry.label),
+                escape_table_cell(&value)
+            ))
+        })
+        .collect();
+    if rows.is_empty() {
+        return String::new();
+    }
+
+    let mut output = String::from("### Infobox\n| Field | Value |\n|---|---|\n");
+    for row in rows {
+        output.push_str(&row);
+    }
+    output.push('\n');
+    output
+}
+
+fn push_disambiguation_option(output: &mut String, number: usize, item: &ResultItem) {
+    output.push_str(&format!("{}. {}\n", number, item.text));
+    if !item.first_url.is_empty() {
+        output.push_str(&format!("   **URL:** {}\n", item.first_url));
+    }
+}
+
+fn format_disambiguation(related_topics: &[RelatedTopic]) -> String {
+    let mut output = String::from(
+        "### Disambiguation\nThis query has several meanings. Choose one of the options below:\n\n",
+    );
+    let mut number = 0;
+    for topic in related_topics {
+        if let RelatedTopic::Topic(item) = topic {
+            number += 1;
+            push_disambiguation_option(&mut output, number, item);
+        }
+    }
+    for topic in related_topics {
+        if let RelatedTopic::Category { name, topics } = topic {
+            output.push_str(&format!("\n#### {}\n", name));
+            for item in topics {
+                number += 1;
+                push_disambiguation_option(&mut output, number, item);
+            }
+        }
+    }
+    output.push('\n');
+    output
+}
+
+/// `### Answer` section, or an empty string when the response has no direct answer.
+pub fn format_answer_section(response: &InstantAnswerResponse) -> String {
+    if response.answer.is_empty() {
+        return String::new();
+    }
+    let mut output = format!("### Answer\n{}\n\n", response.answer);
+    if !response.answer_type.is_empty() {
+        output.push_str(&format!("**Type:** {}\n\n", response.answer_type));
+    }
+    output
+}
+
+/// `### Definition` section, or an empty string when the response has no definition.
+pub fn format_definition_section(response: &InstantAnswerResponse) -> String {
+    if response.definition.is_empty() {
+        return String::new();
+    }
+    let mut output = format!("### Definition\n{}\n\n", response.definition);
+    if !response.definition_source.is_empty() {
+        output.push_str(&format!("**Source:** {}\n", response.definition_source));
+    }
+    if !response.definition_url.is_empty() {
+        output.push_str(&format!("**URL:** {}\n", response.definition_url));
+    }
+    output.push('\n');
+    output
+}
+
+/// Abstract section under the given heading, or an empty string when there is no abstract.
+pub fn format_abstract_section(response: &InstantAnswerResponse, title: &str) -> String {
+    if response.abstract_text.is_empty() {
+        return String::new();
+    }
+    let mut output = format!("### {}\n{}\n\n", title, response.abstract_text);
+    if !response.abstract_source.is_empty() {
+        output.push_str(&format!("**Source:** {}\n", response.abstract_source));
+    }
+    if !response.abstract_url.is_empty() {
+        output.push_str(&format!("**URL:** {}\n", response.abstract_url));
+    }
+    output.push('\n');
+    output
+}
 
 pub fn format_instant_answer(query: &str, response: &InstantAnswerResponse) -> String {
     let mut output = format!("## Instant Answer for \"{query}\"\n\n");
 
+    let has_answer = !response.answer.is_empty();
+    let has_definition = !response.definition.is_empty();
     let has_abstract = !response.abstract_text.is_empty();
+    let infobox = response
+        .infobox
+        .as_ref()
+        .map(format_infobox)
+        .unwrap_or_default();
+    let has_infobox = !infobox.is_empty();
+    let has_results = !response.results.is_empty();
     let has_related = !response.related_topics.is_empty();
 
-    if !has_abstract && !has_related {
+    if !has_answer
+        && !has_definition
+        && !has_abstract
+        && !has_infobox
+        && !has_results
+        && !has_related
+    {
         output.push_str("No instant answer available for this query.\n\n");
         output.push_str("_Source: DuckDuckGo Instant Answer API_");
         return output;
     }
 
-    if has_abstract {
-        output.push_str("### Abstract\n");
-        output.push_str(&response.abstract_text);
-        output.push_str("\n\n");
-        output.push_str(&format!("**Source:** {}\n", response.abstract_source));
-        output.push_str(&format!("**URL:** {}\n\n", response.abstract_url));
+    if let Some(label) = response.response_type_label() {
+        output.push_str(&format!(
+            "**Response Type:** {} ({})\n",
+            response.response_type, label
+        ));
+    }
+    if !response.heading.is_empty() {
+        output.push_str(&format!("**Heading:** {}\n", response.heading));
+    }
+    if !response.entity.is_empty() {
+        output.push_str(&format!("**Entity:** {}\n", response.entity));
+    }
+    if let Some(image) = response.image_url() {
+        output.push_str(&format!("**Image:** {}\n", image));
+    }
+    if response.response_type_label().is_some()
+        || !response.heading.is_empty()
+        || !response.entity.is_empty()
+        || !response.image.is_empty()
+    {
+        output.push('\n');
+    }
+
+    output.push_str(&format_answer_section(response));
+    output.push_str(&format_definition_section(response));
+    output.push_str(&format_abstract_section(response, "Abstract"));
+    output.push_str(&infobox);
+
+    if has_results {
+        output.push_str("### Official Links\n");
+        for item in &response.results {
+            output.push_str(&format!("- [{}]({})\n", item.text, item.first_url));
+        }
+        output.push('\n');
     }
 
-    if has_related {
+    if has_related && response.response_type == "D" {
+        output.push_str(&format_disambiguation(&response.related_topics));
+    } else if has_related {
         output.push_str("### Related Topics\n");
         for topic in &response.related_topics {
             match topic {
@@ -43,12 +194,13 @@ pub fn format_instant_answer(query: &str, response: &InstantAnswerResponse) -> S
     output
 }
 
-pub async fn execute_instant_answer(
+pub async fn fetch_instant_answer(
     client: &reqwest::Client,
     base_url: &str,
     query: &str,
+    disambiguation: Disambiguation,
     timeout_secs: u64,
-) -> Result<String, WebSearchError> {
+) -> Result<InstantAnswerResponse, WebSearchError> {
     if query.is_empty() {
         return Err(WebSearchError::EmptyQuery);
     }
@@ -59,7 +211,7 @@ pub async fn execute_instant_answer(
             ("q", query),
             ("format", "json"),
             ("no_html", "1"),
-            ("skip_disambig", "1"),
+            ("skip_disambig", disambiguation.skip_disambig_value()),
         ])
         .send()
         .await
@@ -73,8 +225,19 @@ pub async fn execute_instant_answer(
 
     let response = response.error_for_status()?;
     let body = response.text().await?;
-    let ia_response: InstantAnswerResponse = serde_json::from_str(&body)?;
-    Ok(format_instant_answer(query, &ia_response))
+    Ok(serde_json::from_str(&body)?)
+}
+
+pub async fn execute_instant_answer(
+    client: &reqwest::Client,
+    base_url: &str,
+    query: &str,
+    disambiguation: Disambiguation,
+    timeout_secs: u64,
+) -> Result<String, WebSearchError> {
+    let response =
+        fetch_instant_answer(client, base_url, query, disambiguation, timeout_secs).await?;
+    Ok(format_instant_answer(query, &response))
 }
 
 #[cfg(test)]
@@ -115,12 +278,71 @@ mod tests {
         let json = include_str!("../../tests/fixtures/instant_answer_disambig.json");
         let response: InstantAnswerResponse = serde_json::from_str(json).unwrap();
         let output = format_instant_answer("java", &response);
-        assert!(output.contains("### Related Topics"));
-        assert!(output.contains("Java (programming language)"));
-        assert!(output.contains("Programming"));
+        assert!(output.contains("**Response Type:** D (disambiguation)\n"));
+        assert!(output.contains("### Disambiguation\n"));
+        assert!(!output.contains("### Related Topics"));
+        assert!(output.contains(
+            "1. Java (programming language) - A high-level, class-based, object-oriented programming language\n   **URL:** https://duckduckgo.com/Java_(programming_language)\n"
+        ));
+        assert!(output.contains("\n#### Programming\n2. Java Platform"));
+        assert!(output.contains("3. Java virtual machine"));
+        assert!(output.contains("   **URL:** https://duckduckgo.com/Java_virtual_machine\n"));
         assert!(output.contains("_Source: DuckDuckGo Instant Answer API_"));
     }
 
+    #[test]
+    fn test_format_heading_image_entity_and_official_links() {
+        let json = include_str!("../../tests/fixtures/instant_answer.json");
+        let response: InstantAnswerResponse = serde_json::from_str(json).unwrap();
+        let output = format_instant_answer("rust", &response);
+        assert!(output.contains("**Heading:** Rust (programming language)\n"));
+        assert!(output.contains("**Entity:** programming language\n"));
+        assert!(output.contains("**Image:** https://duckduckgo.com/i/a0a4d2b4.png\n"));
+        assert!(
+            output.contains("### Official Links\n- [Official site](https://www.rust-lang.org/)\n")
+        );
+    }
+
+    #[test]
+    fn test_format_infobox_table() {
+        let json = include_str!("../../tests/fixtures/instant_answer_infobox.json");
+        let response: InstantAnswerResponse = serde_json::from_str(json).unwrap();
+        let output = format_instant_answer("rust", &response);
+        assert!(output.contains("### Infobox\n| Field | Value |\n|---|---|\n"));
+        assert!(output.contains("| Designed by | Graydon Hoare |\n"));
+        assert!(output.contains("| License | MIT \\| Apache 2.0 |\n"));
+        assert!(output.contains("| GitHub profile | rust-lang |\n"));
+        assert!(!output.contains("Instance of"));
+        assert!(!output.contains("template_name"));
+        let infobox_at = output.find("### Infobox").unwrap();
+        assert!(output.find("### Abstract").unwrap() < infobox_at);
+        assert!(infobox_at < output.find("### Official Links").unwrap());
+    }
+
+    #[test]
+    fn test_format_answer_only_is_success() {
+        let json = include_str!("../../tests/fixtures/instant_answer_answer.json");
+        let response: InstantAnswerResponse = serde_json::from_str(json).unwrap();
+        let output = format_instant_answer("1 km in miles", &response);
+        assert!(!output.contains("No instant answer available"));
+        assert!(output.contains("### Answer\n1 kilometer = 0.621 miles\n"));
+        assert!(output.contains("**Type:** conversions"));
+    }
+
+    #[test]
+    fn test_format_definition() {
+        let json = include_str!("../../tests/fixtures/instant_answer_definition.json");
+        let response: InstantAnswerResponse = serde_json::from_str(json).unwrap();
+        let output = format_instant_answer("define serendipity", &response);
+        assert!(!output.contains("No instant answer available"));
+        assert!(output.contains("### Definition\nserendipity definition:"));
+        assert!(output.contains("**Source:** Merriam-Webster\n"));
+        assert!(
+            output.contains("**URL:** https://www.merriam-webster.com/dictionary/serendipity\n")
+        );
+        assert!(output.contains("**Heading:** Serendipity\n"));
+    }
+
     #[test]
     fn test_format_abstract_only_no_related() {
         let response = InstantAnswerResponse {
@@ -133,6 +355,7 @@ mod tests {
             definition_url: String::new(),
             related_topics: vec![],
             response_type: "A".to_string(),
+            ..Default::default()
         };
         let output = format_instant_answer("test", &response);
         assert!(output.contains("### Abstract"));
@@ -162,6 +385,7 @@ mod tests {
                 }),
             ],
             response_type: "A".to_string(),
+            ..Default::default()
         };
         let output = format_instant_answer("test", &response);
         assert!(output.contains("### Related Topics"));
@@ -186,7 +410,8 @@ mod tests {
                     first_url: "https://example.com/physics".to_string(),
                 }],
             }],
-            response_type: "D".to_string(),
+            response_type: "C".to_string(),
+            ..Default::default()
         };
         let output = format_instant_answer("test", &response);
         assert!(output.contains("### Related Topics"));
@@ -1,2 +1,9 @@
+pub mod fetch_url;
+pub mod image_search;
 pub mod instant_answer;
+pub mod news_search;
+pub mod search;
+pub mod search_suggestions;
+pub mod video_search;
+pub mod vqd;
 pub mod web_search;
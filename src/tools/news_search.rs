@@ -0,0 +1,52 @@
+use crate::error::WebSearchError;
+use crate::http_client::fetch_text;
+use crate::models::news::{format_news_markdown, NewsResponse, NewsResult};
+use crate::models::search::SearchOptions;
+use crate::rate_limiter::RateLimiter;
+use crate::tools::vqd::vqd_params;
+
+pub async fn search_news(
+    client: &reqwest::Client,
+    rate_limiter: &RateLimiter,
+    base_url: &str,
+    query: &str,
+    options: &SearchOptions,
+    timeout_secs: u64,
+) -> Result<Vec<NewsResult>, WebSearchError> {
+    let mut params = vqd_params(
+        client,
+        rate_limiter,
+        base_url,
+        query,
+        options,
+        options.safe_search.kp_value(),
+        timeout_secs,
+    )
+    .await?;
+    params.push(("noamp", "1".to_string()));
+    if let Some(time_range) = options.time_range {
+        params.push(("df", time_range.df_value().to_string()));
+    }
+
+    rate_limiter.acquire().await;
+    let request = client.get(format!("{}/news.js", base_url)).query(&params);
+    let body = fetch_text(request, timeout_secs).await?;
+    let response: NewsResponse = serde_json::from_str(&body)?;
+
+    let mut results = response.results;
+    results.sort_by_key(|result| std::cmp::Reverse(result.date));
+    results.truncate(options.max_results);
+    Ok(results)
+}
+
+pub async fn execute_news_search(
+    client: &reqwest::Client,
+    rate_limiter: &RateLimiter,
+    base_url: &str,
+    query: &str,
+    options: &SearchOptions,
+    timeout_secs: u64,
+) -> Result<String, WebSearchError> {
+    let results = search_news(client, rate_limiter, base_url, query, options, timeout_secs).await?;
+    Ok(format_news_markdown(query, &results))
+}
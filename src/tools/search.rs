@@ -0,0 +1,190 @@
+use crate::error::WebSearchError;
+use crate::models::instant_answer::InstantAnswerResponse;
+use crate::models::search::{format_response_markdown, SearchResponse};
+use crate::tools::instant_answer::{
+    format_abstract_section, format_answer_section, format_definition_section,
+};
+
+pub struct CombinedSearch {
+    pub markdown: String,
+    /// `false` when one side failed; such output should not be cached.
+    pub complete: bool,
+}
+
+/// Direct answer, definition and abstract only; related topics are left to `instant_answer`.
+pub fn format_answer_block(query: &str, response: &InstantAnswerResponse) -> Option<String> {
+    let abstract_title = if response.heading.is_empty() {
+        "Abstract"
+    } else {
+        &response.heading
+    };
+    let sections = [
+        format_answer_section(response),
+        format_definition_section(response),
+        format_abstract_section(response, abstract_title),
+    ]
+    .concat();
+
+    if sections.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "## Answer for \"{query}\"\n\n{sections}_Source: DuckDuckGo Instant Answer API_"
+    ))
+}
+
+/// Combines both halves of a `search` call, tolerating the failure of either one.
+pub fn format_combined_markdown(
+    query: &str,
+    answer: Result<InstantAnswerResponse, WebSearchError>,
+    web: Result<SearchResponse, WebSearchError>,
+    attribution: &str,
+) -> Result<CombinedSearch, WebSearchError> {
+    let (answer, answer_warning) = match answer {
+        Ok(response) => (format_answer_block(query, &response), None),
+        Err(e) => {
+            tracing::warn!(error = %e, "Instant answer failed in combined search");
+            (
+                None,
+                Some(format!("Instant answer failed: {}", e.user_message())),
+            )
+        }
+    };
+
+    let web = match web {
+        Ok(mut response) => {
+            response.warnings.extend(answer_warning.clone());
+            format_response_markdown(query, &response, attribution)
+        }
+        Err(e) => {
+            let Some(answer) = answer else {
+                return Err(e);
+            };
+            tracing::warn!(error = %e, "Web search failed in combined search");
+            return Ok(CombinedSearch {
+                markdown: format!(
+                    "{answer}\n\n> **Warning:** Web search failed: {}",
+                    e.user_message()
+                ),
+                complete: false,
+            });
+        }
+    };
+
+    let markdown = match answer {
+        Some(answer) => format!("{answer}\n\n---\n\n{web}"),
+        None => web,
+    };
+    Ok(CombinedSearch {
+        markdown,
+        complete: answer_warning.is_none(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::search::SearchResult;
+
+    fn answer_fixture() -> InstantAnswerResponse {
+        serde_json::from_str(include_str!("../../tests/fixtures/instant_answer.json")).unwrap()
+    }
+
+    fn web_results() -> SearchResponse {
+        SearchResponse::from(vec![SearchResult {
+            title: "The Rust Programming Language".to_string(),
+            url: "https://www.rust-lang.org/".to_string(),
+            snippet: "A language empowering everyone".to_string(),
+            ..Default::default()
+        }])
+    }
+
+    #[test]
+    fn test_answer_block_uses_heading_for_abstract() {
+        let block = format_answer_block("rust", &answer_fixture()).unwrap();
+        assert!(block.starts_with("## Answer for \"rust\"\n\n### Rust (programming language)\n"));
+        assert!(block.contains("**Source:** Wikipedia\n"));
+        assert!(!block.contains("Related Topics"));
+    }
+
+    #[test]
+    fn test_answer_block_matches_instant_answer_sections() {
+        let response: InstantAnswerResponse = serde_json::from_str(include_str!(
+            "../../tests/fixtures/instant_answer_answer.json"
+        ))
+        .unwrap();
+        let block = format_answer_block("1 km in miles", &response).unwrap();
+        assert!(block.contains(&format_answer_section(&response)));
+        assert!(block.contains("### Answer\n1 kilometer = 0.621 miles\n\n**Type:** conversions\n"));
+    }
+
+    #[test]
+    fn test_answer_block_none_without_answer_content() {
+        let response: InstantAnswerResponse = serde_json::from_str(include_str!(
+            "../../tests/fixtures/instant_answer_disambig.json"
+        ))
+        .unwrap();
+        assert!(format_answer_block("java", &response).is_none());
+    }
+
+    #[test]
+    fn test_combined_puts_answer_above_results() {
+        let combined =
+            format_combined_markdown("rust", Ok(answer_fixture()), Ok(web_results()), "SearXNG")
+                .unwrap();
+        assert!(combined.complete);
+        let answer_at = combined.markdown.find("## Answer for \"rust\"").unwrap();
+        let web_at = combined
+            .markdown
+            .find("## Web Search Results for \"rust\"")
+            .unwrap();
+        assert!(answer_at < web_at);
+        assert!(combined.markdown.ends_with("_Source: SearXNG (1 results)_"));
+    }
+
+    #[test]
+    fn test_combined_keeps_results_when_answer_fails() {
+        let combined = format_combined_markdown(
+            "rust",
+            Err(WebSearchError::Timeout(10)),
+            Ok(web_results()),
+            "DuckDuckGo",
+        )
+        .unwrap();
+        assert!(!combined.complete);
+        assert!(!combined.markdown.contains("## Answer for"));
+        assert!(combined
+            .markdown
+            .contains("### 1. The Rust Programming Language"));
+        assert!(combined
+            .markdown
+            .contains("> **Warning:** Instant answer failed: Request timed out."));
+    }
+
+    #[test]
+    fn test_combined_keeps_answer_when_web_fails() {
+        let combined = format_combined_markdown(
+            "rust",
+            Ok(answer_fixture()),
+            Err(WebSearchError::Blocked("DuckDuckGo".to_string())),
+            "DuckDuckGo",
+        )
+        .unwrap();
+        assert!(!combined.complete);
+        assert!(combined.markdown.starts_with("## Answer for \"rust\""));
+        assert!(combined
+            .markdown
+            .ends_with("> **Warning:** Web search failed: The search engine is temporarily blocking automated requests (CAPTCHA). Please try again later or use another backend."));
+    }
+
+    #[test]
+    fn test_combined_returns_web_error_when_nothing_to_show() {
+        let result = format_combined_markdown(
+            "rust",
+            Err(WebSearchError::Timeout(10)),
+            Err(WebSearchError::EmptyQuery),
+            "DuckDuckGo",
+        );
+        assert!(matches!(result, Err(WebSearchError::EmptyQuery)));
+    }
+}
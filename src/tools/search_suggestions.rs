@@ -0,0 +1,35 @@
+use std::collections::HashSet;
+
+use crate::error::WebSearchError;
+use crate::http_client::fetch_text;
+use crate::models::suggestions::Suggestion;
+use crate::rate_limiter::RateLimiter;
+
+pub async fn fetch_suggestions(
+    client: &reqwest::Client,
+    rate_limiter: &RateLimiter,
+    base_url: &str,
+    query: &str,
+    region: Option<&str>,
+    max_results: usize,
+    timeout_secs: u64,
+) -> Result<Vec<String>, WebSearchError> {
+    if query.trim().is_empty() {
+        return Err(WebSearchError::EmptyQuery);
+    }
+
+    rate_limiter.acquire().await;
+    let request = client
+        .get(format!("{}/ac/", base_url))
+        .query(&[("q", query), ("kl", region.unwrap_or("wt-wt"))]);
+    let body = fetch_text(request, timeout_secs).await?;
+    let suggestions: Vec<Suggestion> = serde_json::from_str(&body)?;
+
+    let mut seen = HashSet::new();
+    Ok(suggestions
+        .into_iter()
+        .map(|s| s.phrase.trim().to_string())
+        .filter(|phrase| !phrase.is_empty() && seen.insert(phrase.to_lowercase()))
+        .take(max_results)
+        .collect())
+}
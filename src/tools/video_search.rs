@@ -0,0 +1,59 @@
+use crate::error::WebSearchError;
+use crate::http_client::fetch_text;
+use crate::models::search::SearchOptions;
+use crate::models::videos::{format_videos_markdown, VideoFilters, VideoResponse, VideoResult};
+use crate::rate_limiter::RateLimiter;
+use crate::tools::vqd::{media_safe_search, vqd_params};
+
+pub async fn search_videos(
+    client: &reqwest::Client,
+    rate_limiter: &RateLimiter,
+    base_url: &str,
+    query: &str,
+    options: &SearchOptions,
+    filters: &VideoFilters,
+    timeout_secs: u64,
+) -> Result<Vec<VideoResult>, WebSearchError> {
+    let mut params = vqd_params(
+        client,
+        rate_limiter,
+        base_url,
+        query,
+        options,
+        media_safe_search(options.safe_search),
+        timeout_secs,
+    )
+    .await?;
+    params.push(("f", filters.f_param()));
+
+    rate_limiter.acquire().await;
+    let request = client.get(format!("{}/v.js", base_url)).query(&params);
+    let body = fetch_text(request, timeout_secs).await?;
+    let response: VideoResponse = serde_json::from_str(&body)?;
+
+    let mut results = response.results;
+    results.truncate(options.max_results);
+    Ok(results)
+}
+
+pub async fn execute_video_search(
+    client: &reqwest::Client,
+    rate_limiter: &RateLimiter,
+    base_url: &str,
+    query: &str,
+    options: &SearchOptions,
+    filters: &VideoFilters,
+    timeout_secs: u64,
+) -> Result<String, WebSearchError> {
+    let results = search_videos(
+        client,
+        rate_limiter,
+        base_url,
+        query,
+        options,
+        filters,
+        timeout_secs,
+    )
+    .await?;
+    Ok(format_videos_markdown(query, &results))
+}
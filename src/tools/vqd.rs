@@ -0,0 +1,111 @@
+use crate::error::WebSearchError;
+use crate::http_client::fetch_text;
+use crate::models::search::{SafeSearch, SearchOptions};
+use crate::rate_limiter::RateLimiter;
+
+const DEFAULT_REGION: &str = "wt-wt";
+
+pub fn extract_vqd(html: &str) -> Option<String> {
+    html.match_indices("vqd=").find_map(|(start, _)| {
+        let token: String = html[start + 4..]
+            .trim_start_matches(['"', '\''])
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+            .collect();
+        (!token.is_empty()).then_some(token)
+    })
+}
+
+pub async fn fetch_vqd(
+    client: &reqwest::Client,
+    base_url: &str,
+    query: &str,
+    timeout_secs: u64,
+) -> Result<String, WebSearchError> {
+    let request = client.get(format!("{}/", base_url)).query(&[("q", query)]);
+    let html = fetch_text(request, timeout_secs).await?;
+    extract_vqd(&html).ok_or_else(|| {
+        WebSearchError::HtmlParseError(format!("vqd token not found in {} byte page", html.len()))
+    })
+}
+
+/// `p` value for the media endpoints (`i.js`, `v.js`), which only distinguish "on" (1)
+/// and "off" (-1).
+pub fn media_safe_search(safe_search: SafeSearch) -> &'static str {
+    match safe_search {
+        SafeSearch::Off => "-1",
+        SafeSearch::Strict | SafeSearch::Moderate => "1",
+    }
+}
+
+/// Fetches a vqd token and returns the query parameters shared by the vqd-protected JSON
+/// endpoints. `safe_search` is the endpoint-specific `p` value.
+pub async fn vqd_params(
+    client: &reqwest::Client,
+    rate_limiter: &RateLimiter,
+    base_url: &str,
+    query: &str,
+    options: &SearchOptions,
+    safe_search: &str,
+    timeout_secs: u64,
+) -> Result<Vec<(&'static str, String)>, WebSearchError> {
+    if query.is_empty() {
+        return Err(WebSearchError::EmptyQuery);
+    }
+
+    rate_limiter.acquire().await;
+    let vqd = fetch_vqd(client, base_url, query, timeout_secs).await?;
+
+    Ok(vec![
+        ("q", query.to_string()),
+        ("vqd", vqd),
+        ("o", "json".to_string()),
+        (
+            "l",
+            options
+                .region
+                .clone()
+                .unwrap_or_else(|| DEFAULT_REGION.to_string()),
+        ),
+        ("p", safe_search.to_string()),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_vqd_double_quoted() {
+        let html = include_str!("../../tests/fixtures/vqd_page.html");
+        assert_eq!(
+            extract_vqd(html).as_deref(),
+            Some("4-211998856789012345678901234567890")
+        );
+    }
+
+    #[test]
+    fn test_extract_vqd_single_quoted_and_query_string() {
+        assert_eq!(
+            extract_vqd("DDG.deep.initialize('/d.js?q=rust&vqd='4-1234');").as_deref(),
+            Some("4-1234")
+        );
+        assert_eq!(
+            extract_vqd("/i.js?q=rust&vqd=4-5678&p=1").as_deref(),
+            Some("4-5678")
+        );
+    }
+
+    #[test]
+    fn test_media_safe_search() {
+        assert_eq!(media_safe_search(SafeSearch::Off), "-1");
+        assert_eq!(media_safe_search(SafeSearch::Moderate), "1");
+        assert_eq!(media_safe_search(SafeSearch::Strict), "1");
+    }
+
+    #[test]
+    fn test_extract_vqd_missing() {
+        assert_eq!(extract_vqd("<html><body>no token</body></html>"), None);
+        assert_eq!(extract_vqd("vqd=\"\""), None);
+    }
+}
@@ -1,63 +1,519 @@
+use chrono::NaiveDate;
+use reqwest::Url;
 use scraper::{Html, Selector};
 
 use crate::error::WebSearchError;
-use crate::models::search::{format_results_markdown, SearchResult};
+use crate::http_client::send;
+use crate::models::search::{
+    format_results_markdown, registrable_domain, SearchOptions, SearchResult,
+};
+use crate::rate_limiter::RateLimiter;
 
-pub fn parse_html_results(html: &str, max_results: usize) -> Vec<SearchResult> {
+const DUCKDUCKGO_ORIGIN: &str = "https://duckduckgo.com";
+const MAX_PAGES: usize = 10;
+const ATTRIBUTION: &str = "DuckDuckGo";
+const BLOCK_PAGE_MARKERS: [&str; 3] = ["bots use DuckDuckGo too", "anomaly-modal", "/anomaly.js"];
+const CHALLENGE_SELECTOR: &str =
+    "#challenge-form, form[action*=\"anomaly\"], [data-testid=\"anomaly-modal\"], .anomaly-modal__modal";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Endpoint {
+    Html,
+    Lite,
+}
+
+impl Endpoint {
+    fn path(self) -> &'static str {
+        match self {
+            Self::Html => "/html/",
+            Self::Lite => "/lite/",
+        }
+    }
+
+    fn parse_results(self, html: &str) -> Result<Vec<SearchResult>, WebSearchError> {
+        match self {
+            Self::Html => parse_html_results(html, usize::MAX),
+            Self::Lite => parse_lite_results(html, usize::MAX),
+        }
+    }
+}
+
+fn absolute_url(href: &str) -> String {
+    if href.starts_with("//") {
+        format!("https:{href}")
+    } else if href.starts_with('/') {
+        format!("{DUCKDUCKGO_ORIGIN}{href}")
+    } else {
+        href.to_string()
+    }
+}
+
+const DATE_FORMATS: &[&str] = &["%b %d, %Y", "%B %d, %Y", "%d %b %Y", "%d %B %Y"];
+
+const AGE_UNITS: &[&str] = &["second", "minute", "hour", "day", "week", "month", "year"];
+
+/// Accepts an ISO timestamp, a written-out date or a relative age such as
+/// "3 days ago". Dates are normalized to `YYYY-MM-DD`; anything else is `None`.
+fn parse_result_date(text: &str) -> Option<String> {
+    let text = text.trim();
+    if let Some(date) = text
+        .get(..10)
+        .and_then(|prefix| NaiveDate::parse_from_str(prefix, "%Y-%m-%d").ok())
+    {
+        return Some(date.to_string());
+    }
+    if let Some(date) = DATE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(text, format).ok())
+    {
+        return Some(date.to_string());
+    }
+    is_relative_age(text).then(|| text.to_string())
+}
+
+fn is_relative_age(text: &str) -> bool {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let [count, unit, "ago"] = words.as_slice() else {
+        return false;
+    };
+    let count_ok = matches!(*count, "a" | "an") || count.parse::<u32>().is_ok();
+    let unit = unit.strip_suffix('s').unwrap_or(unit);
+    count_ok && AGE_UNITS.contains(&unit)
+}
+
+pub fn resolve_result_url(href: &str) -> (String, Option<String>) {
+    let absolute = absolute_url(href);
+
+    let Ok(parsed) = Url::parse(&absolute) else {
+        return (absolute, None);
+    };
+    let is_redirect = parsed
+        .host_str()
+        .is_some_and(|host| host == "duckduckgo.com" || host.ends_with(".duckduckgo.com"))
+        && parsed.path() == "/l/";
+    if !is_redirect {
+        return (absolute, None);
+    }
+
+    match parsed
+        .query_pairs()
+        .find(|(key, _)| key == "uddg")
+        .map(|(_, value)| value.into_owned())
+    {
+        Some(destination) if !destination.is_empty() => (destination, Some(absolute)),
+        _ => (absolute, None),
+    }
+}
+
+pub fn parse_html_results(
+    html: &str,
+    max_results: usize,
+) -> Result<Vec<SearchResult>, WebSearchError> {
     let document = Html::parse_document(html);
     let result_selector = Selector::parse(".result").unwrap();
     let title_selector = Selector::parse(".result__a").unwrap();
     let snippet_selector = Selector::parse(".result__snippet").unwrap();
+    let ad_badge_selector = Selector::parse(".badge--ad").unwrap();
+    let display_url_selector = Selector::parse(".result__url").unwrap();
+    let favicon_selector = Selector::parse(".result__icon__img").unwrap();
+    let date_selector = Selector::parse(".result__timestamp, .result__extras__url > span").unwrap();
+    let no_results_selector = Selector::parse(".no-results").unwrap();
 
-    document
+    let mut results: Vec<SearchResult> = document
         .select(&result_selector)
         .filter_map(|result| {
             let title_el = result.select(&title_selector).next()?;
             let title = title_el.text().collect::<String>().trim().to_string();
-            let url = title_el.value().attr("href")?.to_string();
+            let (url, tracking_url) = resolve_result_url(title_el.value().attr("href")?);
             let snippet = result
                 .select(&snippet_selector)
                 .next()
                 .map(|el| el.text().collect::<String>().trim().to_string())
                 .unwrap_or_default();
+            let is_ad = result.value().classes().any(|c| c == "result--ad")
+                || result.select(&ad_badge_selector).next().is_some();
+            let display_url = result
+                .select(&display_url_selector)
+                .next()
+                .map(|el| el.text().collect::<String>().trim().to_string())
+                .filter(|text| !text.is_empty());
+            let favicon_url = result
+                .select(&favicon_selector)
+                .next()
+                .and_then(|img| img.value().attr("src"))
+                .map(absolute_url);
+            let date = result
+                .select(&date_selector)
+                .filter(|el| !el.value().classes().any(|c| c == "result__icon"))
+                .find_map(|el| parse_result_date(&el.text().collect::<String>()));
             Some(SearchResult {
                 title,
+                domain: registrable_domain(&url),
                 url,
                 snippet,
+                display_url,
+                favicon_url,
+                date,
+                tracking_url,
+                is_ad,
+                ..Default::default()
             })
         })
-        .take(max_results)
-        .collect()
+        .collect();
+
+    let has_no_results_marker = document.select(&no_results_selector).next().is_some();
+    if results.is_empty() && !has_no_results_marker {
+        check_layout(
+            &document,
+            html,
+            &[".result", ".result__a", ".result__snippet"],
+        )?;
+    }
+
+    results.truncate(max_results);
+    Ok(results)
 }
 
-pub async fn execute_web_search(
+/// Rejects a non-blank page without any result block, reporting how many of the
+/// expected elements it holds, so a layout change is not mistaken for "no results".
+fn check_layout(document: &Html, html: &str, selectors: &[&str]) -> Result<(), WebSearchError> {
+    if document.root_element().text().all(|t| t.trim().is_empty()) {
+        return Ok(());
+    }
+    let title_selector = Selector::parse("title").unwrap();
+    let page_title = document
+        .select(&title_selector)
+        .next()
+        .map(|el| el.text().collect::<String>().trim().to_string())
+        .unwrap_or_default();
+    let counts: Vec<String> = selectors
+        .iter()
+        .map(|selector| {
+            let count = document.select(&Selector::parse(selector).unwrap()).count();
+            format!("{selector}: {count}")
+        })
+        .collect();
+    Err(WebSearchError::HtmlParseError(format!(
+        "no recognisable result blocks (page title: {:?}, {} bytes, {})",
+        page_title,
+        html.len(),
+        counts.join(", ")
+    )))
+}
+
+pub fn parse_lite_results(
+    html: &str,
+    max_results: usize,
+) -> Result<Vec<SearchResult>, WebSearchError> {
+    let document = Html::parse_document(html);
+    let row_selector = Selector::parse("tr").unwrap();
+    let link_selector = Selector::parse("a.result-link").unwrap();
+    let snippet_selector = Selector::parse("td.result-snippet").unwrap();
+    let display_url_selector = Selector::parse("span.link-text").unwrap();
+    let no_results_selector = Selector::parse(".no-results").unwrap();
+
+    let mut results: Vec<SearchResult> = Vec::new();
+    let mut awaiting_snippet = false;
+    let mut awaiting_display_url = false;
+    for row in document.select(&row_selector) {
+        if let Some(link) = row.select(&link_selector).next() {
+            awaiting_snippet = false;
+            awaiting_display_url = false;
+            let Some(href) = link.value().attr("href") else {
+                continue;
+            };
+            let (url, tracking_url) = resolve_result_url(href);
+            results.push(SearchResult {
+                title: link.text().collect::<String>().trim().to_string(),
+                domain: registrable_domain(&url),
+                url,
+                tracking_url,
+                is_ad: row.value().classes().any(|c| c == "result-sponsored"),
+                ..Default::default()
+            });
+            awaiting_snippet = true;
+            awaiting_display_url = true;
+        } else if let Some(snippet) = row.select(&snippet_selector).next() {
+            if awaiting_snippet {
+                if let Some(result) = results.last_mut() {
+                    result.snippet = snippet.text().collect::<String>().trim().to_string();
+                }
+                awaiting_snippet = false;
+            }
+        } else if let Some(display_url) = row.select(&display_url_selector).next() {
+            if awaiting_display_url {
+                if let Some(result) = results.last_mut() {
+                    result.display_url =
+                        Some(display_url.text().collect::<String>().trim().to_string())
+                            .filter(|text| !text.is_empty());
+                }
+                awaiting_snippet = false;
+                awaiting_display_url = false;
+            }
+        }
+    }
+
+    let has_no_results_marker = document.select(&no_results_selector).next().is_some()
+        || document
+            .select(&Selector::parse("td").unwrap())
+            .any(|cell| cell.text().collect::<String>().trim() == "No results.");
+    if results.is_empty() && !has_no_results_marker {
+        check_layout(
+            &document,
+            html,
+            &["tr", "a.result-link", "td.result-snippet"],
+        )?;
+    }
+
+    results.truncate(max_results);
+    Ok(results)
+}
+
+/// A page is blocked when it carries the challenge form or modal. The text markers
+/// only count on pages without any result, since snippets may quote them.
+pub fn is_block_page(html: &str) -> bool {
+    let document = Html::parse_document(html);
+    let challenge_selector = Selector::parse(CHALLENGE_SELECTOR).unwrap();
+    if document.select(&challenge_selector).next().is_some() {
+        return true;
+    }
+    let result_selector = Selector::parse(".result, a.result-link").unwrap();
+    document.select(&result_selector).next().is_none()
+        && BLOCK_PAGE_MARKERS
+            .iter()
+            .any(|marker| html.contains(marker))
+}
+
+async fn fetch_page(
+    request: reqwest::RequestBuilder,
+    timeout_secs: u64,
+) -> Result<String, WebSearchError> {
+    let response = send(request, timeout_secs).await?;
+    let status = response.status();
+    if status == reqwest::StatusCode::ACCEPTED || status == reqwest::StatusCode::FORBIDDEN {
+        tracing::warn!(status = %status, "DuckDuckGo answered with a block status");
+        return Err(WebSearchError::Blocked(ATTRIBUTION.to_string()));
+    }
+    let html = response.error_for_status()?.text().await?;
+    if is_block_page(&html) {
+        tracing::warn!(bytes = html.len(), "DuckDuckGo served a bot-detection page");
+        return Err(WebSearchError::Blocked(ATTRIBUTION.to_string()));
+    }
+    Ok(html)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NextPageForm {
+    pub action: String,
+    pub method: String,
+    pub fields: Vec<(String, String)>,
+}
+
+pub fn parse_next_page_form(html: &str) -> Option<NextPageForm> {
+    let document = Html::parse_document(html);
+    let form_selector = Selector::parse("form").unwrap();
+    let submit_selector = Selector::parse("input[type=\"submit\"]").unwrap();
+    let hidden_selector = Selector::parse("input[type=\"hidden\"]").unwrap();
+
+    let form = document.select(&form_selector).find(|form| {
+        form.select(&submit_selector).any(|input| {
+            input
+                .value()
+                .attr("value")
+                .is_some_and(|value| value.trim().to_ascii_lowercase().starts_with("next"))
+        })
+    })?;
+
+    let fields = form
+        .select(&hidden_selector)
+        .filter_map(|input| {
+            let name = input.value().attr("name")?;
+            let value = input.value().attr("value").unwrap_or_default();
+            Some((name.to_string(), value.to_string()))
+        })
+        .collect();
+
+    Some(NextPageForm {
+        action: form.value().attr("action").unwrap_or("/html/").to_string(),
+        method: form
+            .value()
+            .attr("method")
+            .unwrap_or("post")
+            .to_ascii_lowercase(),
+        fields,
+    })
+}
+
+fn apply_search_filters(fields: &mut Vec<(String, String)>, options: &SearchOptions) {
+    let mut set = |name: &str, value: &str| {
+        fields.retain(|(key, _)| key != name);
+        fields.push((name.to_string(), value.to_string()));
+    };
+    if let Some(region) = &options.region {
+        set("kl", region);
+    }
+    set("kp", options.safe_search.kp_value());
+    if let Some(time_range) = options.time_range {
+        set("df", time_range.df_value());
+    }
+}
+
+fn next_page_request(
     client: &reqwest::Client,
     base_url: &str,
+    endpoint: Endpoint,
+    form: &NextPageForm,
+    options: &SearchOptions,
+) -> reqwest::RequestBuilder {
+    let mut fields = form.fields.clone();
+    apply_search_filters(&mut fields, options);
+    let url = if form.action.starts_with('/') {
+        format!("{}{}", base_url, form.action)
+    } else {
+        format!("{}{}", base_url, endpoint.path())
+    };
+    if form.method == "get" {
+        client.get(url).query(&fields)
+    } else {
+        client.post(url).form(&fields)
+    }
+}
+
+pub async fn search_web(
+    client: &reqwest::Client,
+    rate_limiter: &RateLimiter,
+    base_url: &str,
+    lite_base_url: &str,
     query: &str,
-    max_results: usize,
+    options: &SearchOptions,
     timeout_secs: u64,
-) -> Result<String, WebSearchError> {
+) -> Result<Vec<SearchResult>, WebSearchError> {
     if query.is_empty() {
         return Err(WebSearchError::EmptyQuery);
     }
+    if options.page > MAX_PAGES {
+        return Err(WebSearchError::PageOutOfRange(options.page));
+    }
+
+    let primary = search_endpoint(
+        client,
+        rate_limiter,
+        base_url,
+        Endpoint::Html,
+        query,
+        options,
+        timeout_secs,
+    )
+    .await;
+    let Err(primary_error) = primary else {
+        return primary;
+    };
+
+    tracing::warn!(error = %primary_error, "DuckDuckGo HTML endpoint failed, falling back to Lite");
+    rate_limiter.acquire().await;
+    search_endpoint(
+        client,
+        rate_limiter,
+        lite_base_url,
+        Endpoint::Lite,
+        query,
+        options,
+        timeout_secs,
+    )
+    .await
+    .map_err(|fallback_error| {
+        tracing::warn!(error = %fallback_error, "DuckDuckGo Lite fallback failed");
+        primary_error
+    })
+}
 
-    let response = client
-        .get(format!("{}/html/", base_url))
-        .query(&[("q", query)])
-        .send()
-        .await
-        .map_err(|e| {
-            if e.is_timeout() {
-                WebSearchError::Timeout(timeout_secs)
-            } else {
-                WebSearchError::HttpError(e)
+async fn search_endpoint(
+    client: &reqwest::Client,
+    rate_limiter: &RateLimiter,
+    base_url: &str,
+    endpoint: Endpoint,
+    query: &str,
+    options: &SearchOptions,
+    timeout_secs: u64,
+) -> Result<Vec<SearchResult>, WebSearchError> {
+    let first_page = options.page.max(1);
+    let mut params = vec![("q".to_string(), options.query_with_site_operators(query))];
+    apply_search_filters(&mut params, options);
+    let request = client
+        .get(format!("{}{}", base_url, endpoint.path()))
+        .query(&params);
+    let mut html = fetch_page(request, timeout_secs).await?;
+    let mut results = Vec::new();
+    let mut ads = Vec::new();
+    let mut position = 0;
+
+    for page in 1..=MAX_PAGES {
+        if page < first_page {
+            position += endpoint
+                .parse_results(&html)?
+                .iter()
+                .filter(|result| !result.is_ad)
+                .count();
+        } else {
+            for mut result in endpoint.parse_results(&html)? {
+                if !result.is_ad {
+                    position += 1;
+                    result.position = Some(position);
+                }
+                if !options.allows_url(&result.url) {
+                    continue;
+                }
+                if result.is_ad {
+                    if options.include_ads {
+                        ads.push(result);
+                    }
+                } else if results.len() < options.max_results {
+                    results.push(result);
+                }
+            }
+            if results.len() >= options.max_results {
+                break;
             }
-        })?;
+        }
+
+        if page == MAX_PAGES {
+            break;
+        }
+        let Some(form) = parse_next_page_form(&html) else {
+            break;
+        };
+        rate_limiter.acquire().await;
+        html = fetch_page(
+            next_page_request(client, base_url, endpoint, &form, options),
+            timeout_secs,
+        )
+        .await?;
+    }
+
+    results.extend(ads);
+    Ok(results)
+}
 
-    let response = response.error_for_status()?;
-    let html = response.text().await?;
-    let results = parse_html_results(&html, max_results);
-    Ok(format_results_markdown(query, &results))
+pub async fn execute_web_search(
+    client: &reqwest::Client,
+    rate_limiter: &RateLimiter,
+    base_url: &str,
+    lite_base_url: &str,
+    query: &str,
+    options: &SearchOptions,
+    timeout_secs: u64,
+) -> Result<String, WebSearchError> {
+    let results = search_web(
+        client,
+        rate_limiter,
+        base_url,
+        lite_base_url,
+        query,
+        options,
+        timeout_secs,
+    )
+    .await?;
+    Ok(format_results_markdown(query, &results, ATTRIBUTION))
 }
 
 #[cfg(test)]
@@ -67,7 +523,7 @@ mod tests {
     #[test]
     fn test_parse_extracts_all_fields() {
         let html = include_str!("../../tests/fixtures/search_results.html");
-        let results = parse_html_results(html, 10);
+        let results = parse_html_results(html, 10).unwrap();
         assert_eq!(results.len(), 3);
         assert_eq!(results[0].title, "The Rust Programming Language");
         assert_eq!(results[0].url, "https://www.rust-lang.org/");
@@ -79,7 +535,7 @@ mod tests {
     #[test]
     fn test_parse_multiple_results() {
         let html = include_str!("../../tests/fixtures/search_results.html");
-        let results = parse_html_results(html, 10);
+        let results = parse_html_results(html, 10).unwrap();
         assert_eq!(results[1].title, "Rust (programming language) - Wikipedia");
         assert_eq!(
             results[2].title,
@@ -90,20 +546,20 @@ mod tests {
     #[test]
     fn test_parse_respects_max_results() {
         let html = include_str!("../../tests/fixtures/search_results.html");
-        let results = parse_html_results(html, 2);
+        let results = parse_html_results(html, 2).unwrap();
         assert_eq!(results.len(), 2);
     }
 
     #[test]
     fn test_parse_empty_html() {
-        let results = parse_html_results("<html><body></body></html>", 10);
+        let results = parse_html_results("<html><body></body></html>", 10).unwrap();
         assert!(results.is_empty());
     }
 
     #[test]
     fn test_parse_empty_search_fixture() {
         let html = include_str!("../../tests/fixtures/search_results_empty.html");
-        let results = parse_html_results(html, 10);
+        let results = parse_html_results(html, 10).unwrap();
         assert!(results.is_empty());
     }
 
@@ -117,7 +573,7 @@ mod tests {
           <a class="result__snippet" href="https://example.com">  Spaced snippet  </a>
         </div>
         "#;
-        let results = parse_html_results(html, 10);
+        let results = parse_html_results(html, 10).unwrap();
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].title, "Spaced Title");
         assert_eq!(results[0].snippet, "Spaced snippet");
@@ -132,7 +588,7 @@ mod tests {
           </h2>
         </div>
         "#;
-        let results = parse_html_results(html, 10);
+        let results = parse_html_results(html, 10).unwrap();
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].title, "Title Only");
         assert_eq!(results[0].snippet, "");
@@ -146,15 +602,280 @@ mod tests {
             <a class="result__a">No Href</a>
           </h2>
         </div>
+        <div class="result">
+          <h2 class="result__title">
+            <a class="result__a" href="https://example.com">With Href</a>
+          </h2>
+        </div>
         "#;
-        let results = parse_html_results(html, 10);
-        assert!(results.is_empty());
+        let results = parse_html_results(html, 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].title, "With Href");
+    }
+
+    #[test]
+    fn test_parse_only_unusable_blocks_is_parse_error() {
+        let html = r#"
+        <div class="result">
+          <h2 class="result__title">
+            <a class="result__a">No Href</a>
+          </h2>
+        </div>
+        "#;
+        let err = parse_html_results(html, 10).unwrap_err();
+        let WebSearchError::HtmlParseError(detail) = err else {
+            panic!("expected HtmlParseError, got {err:?}");
+        };
+        assert!(detail.contains(".result: 1, .result__a: 1, .result__snippet: 0"));
+    }
+
+    #[test]
+    fn test_parse_decodes_redirect_links() {
+        let html = include_str!("../../tests/fixtures/search_results_redirect.html");
+        let results = parse_html_results(html, 10).unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].url, "https://www.rust-lang.org/");
+        assert!(results[0]
+            .tracking_url
+            .as_deref()
+            .unwrap()
+            .starts_with("https://duckduckgo.com/l/?uddg="));
+        assert_eq!(
+            results[1].url,
+            "https://en.wikipedia.org/wiki/Rust_(programming_language)?section=1&lang=en"
+        );
+    }
+
+    #[test]
+    fn test_parse_resolves_protocol_relative_links() {
+        let html = include_str!("../../tests/fixtures/search_results_redirect.html");
+        let results = parse_html_results(html, 10).unwrap();
+        assert_eq!(results[2].url, "https://doc.rust-lang.org/book/");
+        assert!(results[2].tracking_url.is_none());
+    }
+
+    #[test]
+    fn test_parse_direct_links_have_no_tracking_url() {
+        let html = include_str!("../../tests/fixtures/search_results.html");
+        let results = parse_html_results(html, 10).unwrap();
+        assert!(results.iter().all(|r| r.tracking_url.is_none()));
+    }
+
+    #[test]
+    fn test_resolve_relative_redirect() {
+        let (url, tracking_url) =
+            resolve_result_url("/l/?uddg=https%3A%2F%2Fexample.com%2Fa%20b&rut=abc");
+        assert_eq!(url, "https://example.com/a b");
+        assert_eq!(
+            tracking_url.as_deref(),
+            Some("https://duckduckgo.com/l/?uddg=https%3A%2F%2Fexample.com%2Fa%20b&rut=abc")
+        );
+    }
+
+    #[test]
+    fn test_resolve_redirect_without_uddg_keeps_original() {
+        let (url, tracking_url) = resolve_result_url("//duckduckgo.com/l/?rut=abc");
+        assert_eq!(url, "https://duckduckgo.com/l/?rut=abc");
+        assert!(tracking_url.is_none());
+    }
+
+    #[test]
+    fn test_parse_next_page_form_extracts_hidden_fields() {
+        let html = include_str!("../../tests/fixtures/search_results_page1.html");
+        let form = parse_next_page_form(html).unwrap();
+        assert_eq!(form.action, "/html/");
+        assert_eq!(form.method, "post");
+        assert!(form.fields.contains(&("s".to_string(), "3".to_string())));
+        assert!(form.fields.contains(&("dc".to_string(), "4".to_string())));
+        assert!(form.fields.contains(&(
+            "vqd".to_string(),
+            "4-123456789012345678901234567890".to_string()
+        )));
+        assert!(form
+            .fields
+            .contains(&("q".to_string(), "rust programming".to_string())));
+    }
+
+    #[test]
+    fn test_parse_next_page_form_ignores_previous_form() {
+        let html = include_str!("../../tests/fixtures/search_results_page2.html");
+        assert!(parse_next_page_form(html).is_none());
+    }
+
+    #[test]
+    fn test_parse_next_page_form_missing() {
+        let html = include_str!("../../tests/fixtures/search_results.html");
+        assert!(parse_next_page_form(html).is_none());
+    }
+
+    #[test]
+    fn test_parse_lite_results() {
+        let html = include_str!("../../tests/fixtures/search_results_lite.html");
+        let results = parse_lite_results(html, 10).unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].title, "The Rust Programming Language");
+        assert_eq!(results[0].url, "https://www.rust-lang.org/");
+        assert!(results[0].tracking_url.is_some());
+        assert!(results[0]
+            .snippet
+            .contains("reliable and efficient software"));
+        assert_eq!(results[2].url, "https://doc.rust-lang.org/book/");
+        assert_eq!(results[2].snippet, "");
+    }
+
+    #[test]
+    fn test_parse_lite_results_respects_max_results() {
+        let html = include_str!("../../tests/fixtures/search_results_lite.html");
+        assert_eq!(parse_lite_results(html, 2).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_parse_next_page_form_lite_layout() {
+        let html = include_str!("../../tests/fixtures/search_results_lite.html");
+        let form = parse_next_page_form(html).unwrap();
+        assert_eq!(form.action, "/lite/");
+        assert!(form.fields.contains(&("s".to_string(), "23".to_string())));
+    }
+
+    #[test]
+    fn test_parse_changed_markup_reports_diagnostics() {
+        let html = include_str!("../../tests/fixtures/search_results_changed_markup.html");
+        let err = parse_html_results(html, 10).unwrap_err();
+        let WebSearchError::HtmlParseError(detail) = err else {
+            panic!("expected HtmlParseError, got {err:?}");
+        };
+        assert!(detail.contains("page title: \"rust programming at DuckDuckGo\""));
+        assert!(detail.contains(&format!("{} bytes", html.len())));
+        assert!(detail.contains(".result: 0, .result__a: 0, .result__snippet: 0"));
+    }
+
+    #[test]
+    fn test_parse_lite_changed_markup_reports_diagnostics() {
+        let html = include_str!("../../tests/fixtures/search_results_changed_markup.html");
+        let err = parse_lite_results(html, 10).unwrap_err();
+        let WebSearchError::HtmlParseError(detail) = err else {
+            panic!("expected HtmlParseError, got {err:?}");
+        };
+        assert!(detail.contains("tr: 0, a.result-link: 0, td.result-snippet: 0"));
+    }
+
+    #[test]
+    fn test_parse_lite_no_results_page_is_empty() {
+        let html = "<html><body><table><tr><td>No results.</td></tr></table></body></html>";
+        assert!(parse_lite_results(html, 10).unwrap().is_empty());
+        assert!(parse_lite_results("", 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_block_page_detection() {
+        let blocked = include_str!("../../tests/fixtures/search_results_blocked.html");
+        assert!(is_block_page(blocked));
+        let normal = include_str!("../../tests/fixtures/search_results.html");
+        assert!(!is_block_page(normal));
+        let empty = include_str!("../../tests/fixtures/search_results_empty.html");
+        assert!(!is_block_page(empty));
+        let quoted = include_str!("../../tests/fixtures/search_results_marker_snippet.html");
+        assert!(!is_block_page(quoted));
+        assert!(is_block_page(
+            "<html><body><p>Unfortunately, bots use DuckDuckGo too.</p></body></html>"
+        ));
+    }
+
+    #[test]
+    fn test_parse_extracts_metadata() {
+        let html = include_str!("../../tests/fixtures/search_results_metadata.html");
+        let results = parse_html_results(html, 10).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].display_url.as_deref(), Some("www.rust-lang.org"));
+        assert_eq!(results[0].domain.as_deref(), Some("rust-lang.org"));
+        assert_eq!(
+            results[0].favicon_url.as_deref(),
+            Some("https://external-content.duckduckgo.com/ip3/www.rust-lang.org.ico")
+        );
+        assert_eq!(results[0].date.as_deref(), Some("2024-05-02"));
+        assert_eq!(
+            results[1].display_url.as_deref(),
+            Some("news.bbc.co.uk/technology/rust")
+        );
+        assert_eq!(results[1].domain.as_deref(), Some("bbc.co.uk"));
+        assert_eq!(results[1].date, None);
+    }
+
+    #[test]
+    fn test_parse_lite_extracts_display_url_and_domain() {
+        let html = include_str!("../../tests/fixtures/search_results_lite.html");
+        let results = parse_lite_results(html, 10).unwrap();
+        assert_eq!(results[0].display_url.as_deref(), Some("www.rust-lang.org"));
+        assert_eq!(results[1].domain.as_deref(), Some("wikipedia.org"));
+        assert_eq!(
+            results[2].display_url.as_deref(),
+            Some("doc.rust-lang.org/book/")
+        );
+    }
+
+    #[test]
+    fn test_parse_result_date() {
+        assert_eq!(
+            parse_result_date(" 2024-05-02T00:00:00.0000000 ").as_deref(),
+            Some("2024-05-02")
+        );
+        assert_eq!(
+            parse_result_date("May 2, 2024").as_deref(),
+            Some("2024-05-02")
+        );
+        assert_eq!(
+            parse_result_date("3 days ago").as_deref(),
+            Some("3 days ago")
+        );
+        assert_eq!(
+            parse_result_date("an hour ago").as_deref(),
+            Some("an hour ago")
+        );
+        assert_eq!(parse_result_date("\u{a0} "), None);
+        assert_eq!(parse_result_date("2024-13-45"), None);
+        assert_eq!(parse_result_date("Official site"), None);
+        assert_eq!(parse_result_date("5 stars ago"), None);
+    }
+
+    #[test]
+    fn test_parse_ignores_non_date_spans() {
+        let html = include_str!("../../tests/fixtures/search_results_non_date_span.html");
+        let results = parse_html_results(html, 10).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].date, None);
+        assert_eq!(results[1].date.as_deref(), Some("2 weeks ago"));
+    }
+
+    #[test]
+    fn test_parse_marks_ads() {
+        let html = include_str!("../../tests/fixtures/search_results_ads.html");
+        let results = parse_html_results(html, 10).unwrap();
+        assert_eq!(results.len(), 4);
+        assert!(results[0].is_ad);
+        assert!(results[1].is_ad);
+        assert!(!results[2].is_ad);
+        assert!(!results[3].is_ad);
+    }
+
+    #[test]
+    fn test_parse_lite_marks_sponsored_rows() {
+        let html = r#"
+        <table>
+          <tr class="result-sponsored"><td>1.</td><td><a class="result-link" href="https://ads.example/">Ad</a></td></tr>
+          <tr class="result-sponsored"><td></td><td class="result-snippet">Buy now</td></tr>
+          <tr><td>2.</td><td><a class="result-link" href="https://example.com/">Organic</a></td></tr>
+        </table>
+        "#;
+        let results = parse_lite_results(html, 10).unwrap();
+        assert!(results[0].is_ad);
+        assert_eq!(results[0].snippet, "Buy now");
+        assert!(!results[1].is_ad);
     }
 
     #[test]
     fn test_parse_max_results_zero() {
         let html = include_str!("../../tests/fixtures/search_results.html");
-        let results = parse_html_results(html, 0);
+        let results = parse_html_results(html, 0).unwrap();
         assert!(results.is_empty());
     }
 }
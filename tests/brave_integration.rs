@@ -0,0 +1,205 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::Client;
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use websearch_mcp::backends::brave::BraveBackend;
+use websearch_mcp::backends::SearchBackend;
+use websearch_mcp::error::WebSearchError;
+use websearch_mcp::models::search::{
+    format_response_markdown, SafeSearch, SearchOptions, TimeRange,
+};
+use websearch_mcp::rate_limiter::RateLimiter;
+
+fn build_backend(base_url: String) -> BraveBackend {
+    let client = Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .unwrap();
+    BraveBackend::new(
+        client,
+        base_url,
+        "test-token".to_string(),
+        Arc::new(RateLimiter::new(Duration::from_millis(0))),
+        10,
+    )
+}
+
+fn options(max_results: usize) -> SearchOptions {
+    SearchOptions {
+        max_results,
+        ..Default::default()
+    }
+}
+
+async fn mount_status(server: &MockServer, status: u16) {
+    Mock::given(method("GET"))
+        .and(path("/res/v1/web/search"))
+        .respond_with(ResponseTemplate::new(status))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_brave_sends_token_and_maps_results() {
+    let server = MockServer::start().await;
+    let json = include_str!("fixtures/brave_results.json");
+
+    Mock::given(method("GET"))
+        .and(path("/res/v1/web/search"))
+        .and(header("X-Subscription-Token", "test-token"))
+        .and(query_param("q", "rust programming"))
+        .and(query_param("count", "20"))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(json))
+        .mount(&server)
+        .await;
+
+    let backend = build_backend(server.uri());
+    let response = backend
+        .search("rust programming", &options(10))
+        .await
+        .unwrap();
+
+    assert_eq!(response.results.len(), 3);
+    assert_eq!(response.results[0].title, "Rust Programming Language");
+    assert!(!response.results[0].snippet.contains("<strong>"));
+    assert_eq!(
+        response.results[0].date.as_deref(),
+        Some("2026-10-10T08:00:00")
+    );
+    assert_eq!(response.results[0].extra_snippets.len(), 2);
+    assert_eq!(response.results[1].date.as_deref(), Some("3 days ago"));
+    assert_eq!(response.results[2].date, None);
+
+    let markdown = format_response_markdown("rust programming", &response, backend.attribution());
+    assert!(markdown.contains("**Date:** 3 days ago"));
+    assert!(markdown.contains("- Hundreds of companies around the world are using Rust"));
+    assert!(markdown.contains("_Source: Brave Search (3 results)_"));
+}
+
+#[tokio::test]
+async fn test_brave_sends_filters() {
+    let server = MockServer::start().await;
+    let json = include_str!("fixtures/brave_results.json");
+
+    Mock::given(method("GET"))
+        .and(path("/res/v1/web/search"))
+        .and(query_param("q", "rust -site:wikipedia.org"))
+        .and(query_param("offset", "1"))
+        .and(query_param("country", "JP"))
+        .and(query_param("safesearch", "strict"))
+        .and(query_param("freshness", "pw"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(json))
+        .mount(&server)
+        .await;
+
+    let backend = build_backend(server.uri());
+    let response = backend
+        .search(
+            "rust",
+            &SearchOptions {
+                max_results: 10,
+                page: 2,
+                region: Some("jp-jp".to_string()),
+                safe_search: SafeSearch::Strict,
+                time_range: Some(TimeRange::Week),
+                include_domains: Vec::new(),
+                exclude_domains: vec!["wikipedia.org".to_string()],
+                include_ads: false,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.results.len(), 2);
+    assert!(response
+        .results
+        .iter()
+        .all(|r| !r.url.contains("wikipedia")));
+}
+
+#[tokio::test]
+async fn test_brave_unauthorized() {
+    let server = MockServer::start().await;
+    mount_status(&server, 401).await;
+
+    let result = build_backend(server.uri())
+        .search("rust", &options(10))
+        .await;
+
+    assert!(matches!(result, Err(WebSearchError::Unauthorized(_))));
+}
+
+#[tokio::test]
+async fn test_brave_payment_required() {
+    let server = MockServer::start().await;
+    mount_status(&server, 402).await;
+
+    let result = build_backend(server.uri())
+        .search("rust", &options(10))
+        .await;
+
+    assert!(matches!(result, Err(WebSearchError::PaymentRequired(_))));
+}
+
+#[tokio::test]
+async fn test_brave_rate_limited() {
+    let server = MockServer::start().await;
+    mount_status(&server, 429).await;
+
+    let result = build_backend(server.uri())
+        .search("rust", &options(10))
+        .await;
+
+    let err = result.unwrap_err();
+    assert!(matches!(err, WebSearchError::RateLimited(_)));
+    assert!(err.is_retryable());
+}
+
+#[tokio::test]
+async fn test_brave_server_error_is_http_error() {
+    let server = MockServer::start().await;
+    mount_status(&server, 500).await;
+
+    let result = build_backend(server.uri())
+        .search("rust", &options(10))
+        .await;
+
+    assert!(matches!(result, Err(WebSearchError::HttpError(_))));
+}
+
+#[tokio::test]
+async fn test_brave_timeout() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/res/v1/web/search"))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(10)))
+        .mount(&server)
+        .await;
+
+    let client = Client::builder()
+        .timeout(Duration::from_secs(1))
+        .build()
+        .unwrap();
+    let backend = BraveBackend::new(
+        client,
+        server.uri(),
+        "test-token".to_string(),
+        Arc::new(RateLimiter::new(Duration::from_millis(0))),
+        1,
+    );
+    let result = backend.search("rust", &options(10)).await;
+
+    assert!(matches!(result, Err(WebSearchError::Timeout(1))));
+}
+
+#[tokio::test]
+async fn test_brave_rejects_empty_query() {
+    let server = MockServer::start().await;
+    let result = build_backend(server.uri()).search("", &options(10)).await;
+
+    assert!(matches!(result, Err(WebSearchError::EmptyQuery)));
+}
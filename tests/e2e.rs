@@ -1,28 +1,211 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
 use rmcp::model::CallToolRequestParams;
 use rmcp::service::RunningService;
 use rmcp::ServiceExt;
 use wiremock::matchers::{method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
+use websearch_mcp::backends::{BackendCapabilities, BackendRegistry, SearchBackend};
 use websearch_mcp::config::Config;
+use websearch_mcp::error::WebSearchError;
 use websearch_mcp::http_client::build_http_client;
-use websearch_mcp::server::Server;
+use websearch_mcp::models::search::{SafeSearch, SearchOptions, SearchResponse, SearchResult};
+use websearch_mcp::server::{DuckDuckGoUrls, Server};
 
-async fn setup_e2e(
-    html_mock: &MockServer,
-    api_mock: &MockServer,
+struct InMemoryBackend;
+
+#[async_trait]
+impl SearchBackend for InMemoryBackend {
+    fn name(&self) -> &str {
+        "memory"
+    }
+
+    fn attribution(&self) -> &str {
+        "In-Memory"
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::default()
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        _options: &SearchOptions,
+    ) -> Result<SearchResponse, WebSearchError> {
+        Ok(SearchResponse::from(vec![SearchResult {
+            title: format!("In-memory result for {query}"),
+            url: "https://example.com/memory".to_string(),
+            snippet: "Served without touching the network.".to_string(),
+            ..Default::default()
+        }]))
+    }
+}
+
+struct FailingBackend(&'static str);
+
+#[async_trait]
+impl SearchBackend for FailingBackend {
+    fn name(&self) -> &str {
+        self.0
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::default()
+    }
+
+    async fn search(
+        &self,
+        _query: &str,
+        _options: &SearchOptions,
+    ) -> Result<SearchResponse, WebSearchError> {
+        Err(WebSearchError::HtmlParseError(
+            "unexpected markup".to_string(),
+        ))
+    }
+}
+
+struct SponsoredBackend;
+
+#[async_trait]
+impl SearchBackend for SponsoredBackend {
+    fn name(&self) -> &str {
+        "sponsored"
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::default()
+    }
+
+    async fn search(
+        &self,
+        _query: &str,
+        options: &SearchOptions,
+    ) -> Result<SearchResponse, WebSearchError> {
+        let mut results = Vec::new();
+        if options.include_ads {
+            results.push(SearchResult {
+                title: "Sponsored memory".to_string(),
+                url: "https://example.com/memory".to_string(),
+                is_ad: true,
+                ..Default::default()
+            });
+        }
+        results.push(SearchResult {
+            title: "Organic result".to_string(),
+            url: "https://example.com/organic".to_string(),
+            ..Default::default()
+        });
+        Ok(SearchResponse::from(results))
+    }
+}
+
+async fn setup_e2e_with_backend(
+    backend_name: &str,
 ) -> RunningService<rmcp::RoleClient, impl rmcp::Service<rmcp::RoleClient>> {
     let config = Config {
+        backend: backend_name.to_string(),
+        ..test_config()
+    };
+    let client = build_http_client(&config).unwrap();
+    let mut backends = BackendRegistry::new();
+    backends.register(Arc::new(InMemoryBackend));
+    let server = Server::with_backends(
+        client,
+        config,
+        DuckDuckGoUrls::single_origin("http://127.0.0.1:9"),
+        backends,
+    );
+    serve(server).await
+}
+
+async fn setup_e2e_fan_out(
+    backends: Vec<Arc<dyn SearchBackend>>,
+) -> RunningService<rmcp::RoleClient, impl rmcp::Service<rmcp::RoleClient>> {
+    let config = test_config();
+    let client = build_http_client(&config).unwrap();
+    let mut registry = BackendRegistry::new();
+    for backend in backends {
+        registry.register(backend);
+    }
+    let server = Server::with_backends(
+        client,
+        config,
+        DuckDuckGoUrls::single_origin("http://127.0.0.1:9"),
+        registry,
+    );
+    serve(server).await
+}
+
+fn fan_out_request(query: &str) -> CallToolRequestParams {
+    CallToolRequestParams {
+        meta: None,
+        name: "web_search".into(),
+        arguments: Some(
+            serde_json::json!({ "query": query, "fan_out": true })
+                .as_object()
+                .unwrap()
+                .clone(),
+        ),
+        task: None,
+    }
+}
+
+fn web_search_request(query: &str) -> CallToolRequestParams {
+    CallToolRequestParams {
+        meta: None,
+        name: "web_search".into(),
+        arguments: Some(
+            serde_json::json!({ "query": query })
+                .as_object()
+                .unwrap()
+                .clone(),
+        ),
+        task: None,
+    }
+}
+
+fn test_config() -> Config {
+    Config {
         max_results: 10,
         timeout_secs: 10,
         user_agent: "test-agent".to_string(),
         cache_ttl_secs: 0,
+        suggestion_cache_ttl_secs: 0,
         rate_limit_ms: 0,
         max_retries: 3,
-    };
+        region: None,
+        safe_search: SafeSearch::Moderate,
+        backend: "duckduckgo".to_string(),
+        searxng_url: None,
+        brave_api_key: None,
+        brave_base_url: "https://api.search.brave.com".to_string(),
+        brave_rate_limit_ms: 1000,
+    }
+}
+
+async fn setup_e2e(
+    html_mock: &MockServer,
+    api_mock: &MockServer,
+) -> RunningService<rmcp::RoleClient, impl rmcp::Service<rmcp::RoleClient>> {
+    let config = test_config();
     let client = build_http_client(&config).unwrap();
-    let server = Server::with_base_urls(client, config, html_mock.uri(), api_mock.uri());
+    let server = Server::with_base_urls(
+        client,
+        config,
+        DuckDuckGoUrls {
+            api: api_mock.uri(),
+            ..DuckDuckGoUrls::single_origin(&html_mock.uri())
+        },
+    );
+    serve(server).await
+}
 
+async fn serve(
+    server: Server,
+) -> RunningService<rmcp::RoleClient, impl rmcp::Service<rmcp::RoleClient>> {
     let (server_transport, client_transport) = tokio::io::duplex(4096);
     tokio::spawn(async move {
         let svc = server.serve(server_transport).await.unwrap();
@@ -33,17 +216,416 @@ async fn setup_e2e(
 }
 
 #[tokio::test]
-async fn test_e2e_list_tools_returns_two_tools() {
+async fn test_e2e_list_tools_returns_all_tools() {
     let html_mock = MockServer::start().await;
     let api_mock = MockServer::start().await;
     let client = setup_e2e(&html_mock, &api_mock).await;
 
     let tools = client.list_all_tools().await.unwrap();
-    assert_eq!(tools.len(), 2);
+    assert_eq!(tools.len(), 8);
 
     let names: Vec<String> = tools.iter().map(|t| t.name.to_string()).collect();
     assert!(names.contains(&"web_search".to_string()));
     assert!(names.contains(&"instant_answer".to_string()));
+    assert!(names.contains(&"news_search".to_string()));
+    assert!(names.contains(&"image_search".to_string()));
+    assert!(names.contains(&"video_search".to_string()));
+    assert!(names.contains(&"search_suggestions".to_string()));
+    assert!(names.contains(&"search".to_string()));
+    assert!(names.contains(&"fetch_url".to_string()));
+
+    client.cancel().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_e2e_search_suggestions_are_cached() {
+    let html_mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/ac/"))
+        .and(query_param("q", "rus"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(include_str!("fixtures/suggestions.json")),
+        )
+        .expect(1)
+        .mount(&html_mock)
+        .await;
+
+    let config = Config {
+        suggestion_cache_ttl_secs: 60,
+        ..test_config()
+    };
+    let client = build_http_client(&config).unwrap();
+    let client = serve(Server::with_base_urls(
+        client,
+        config,
+        DuckDuckGoUrls::single_origin(&html_mock.uri()),
+    ))
+    .await;
+
+    for _ in 0..2 {
+        let request = CallToolRequestParams {
+            meta: None,
+            name: "search_suggestions".into(),
+            arguments: Some(
+                serde_json::json!({ "query": "rus", "max_results": 3 })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+            ),
+            task: None,
+        };
+        let result = client.call_tool(request).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = result
+            .content
+            .first()
+            .and_then(|c| c.raw.as_text())
+            .map(|t| t.text.as_str())
+            .expect("Expected text content");
+        assert!(text.contains("1. rust\n2. rust programming\n3. rust game\n"));
+    }
+
+    client.cancel().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_e2e_news_search_success() {
+    let html_mock = MockServer::start().await;
+    let api_mock = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("q", "rust"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(include_str!("fixtures/vqd_page.html")),
+        )
+        .mount(&html_mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/news.js"))
+        .and(query_param("q", "rust"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(include_str!("fixtures/news_results.json")),
+        )
+        .mount(&html_mock)
+        .await;
+
+    let client = setup_e2e(&html_mock, &api_mock).await;
+    let request = CallToolRequestParams {
+        meta: None,
+        name: "news_search".into(),
+        arguments: Some(
+            serde_json::json!({ "query": "rust" })
+                .as_object()
+                .unwrap()
+                .clone(),
+        ),
+        task: None,
+    };
+
+    let result = client.call_tool(request).await.unwrap();
+    assert_eq!(result.is_error, Some(false));
+    let text = result
+        .content
+        .first()
+        .and_then(|c| c.raw.as_text())
+        .map(|t| t.text.as_str())
+        .expect("Expected text content");
+    assert!(text.contains("## News Results for \"rust\""));
+    assert!(text.contains("**Source:** Ars Technica"));
+
+    client.cancel().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_e2e_image_search_returns_thumbnail_content() {
+    let html_mock = MockServer::start().await;
+    let api_mock = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("q", "rust logo"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(include_str!("fixtures/vqd_page.html")),
+        )
+        .mount(&html_mock)
+        .await;
+    let images = serde_json::json!({
+        "results": [{
+            "title": "Broken Thumbnail",
+            "image": "https://example.com/broken.png",
+            "thumbnail": format!("{}/missing.png", html_mock.uri()),
+            "url": "https://example.com/",
+            "width": 16,
+            "height": 16,
+            "source": "Bing"
+        }, {
+            "title": "Rust Logo",
+            "image": "https://www.rust-lang.org/logo.png",
+            "thumbnail": format!("{}/thumb.png", html_mock.uri()),
+            "url": "https://www.rust-lang.org/",
+            "width": 512,
+            "height": 512,
+            "source": "Bing"
+        }]
+    });
+    Mock::given(method("GET"))
+        .and(path("/i.js"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(images))
+        .mount(&html_mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/thumb.png"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(b"\x89PNG".to_vec(), "image/png"))
+        .mount(&html_mock)
+        .await;
+
+    let client = setup_e2e(&html_mock, &api_mock).await;
+    let request = CallToolRequestParams {
+        meta: None,
+        name: "image_search".into(),
+        arguments: Some(
+            serde_json::json!({ "query": "rust logo", "include_thumbnails": true })
+                .as_object()
+                .unwrap()
+                .clone(),
+        ),
+        task: None,
+    };
+
+    let result = client.call_tool(request).await.unwrap();
+    assert_eq!(result.is_error, Some(false));
+    assert_eq!(result.content.len(), 3);
+    let text = result.content[0]
+        .raw
+        .as_text()
+        .map(|t| t.text.as_str())
+        .expect("Expected text content");
+    assert!(text.contains("## Image Results for \"rust logo\""));
+    let label = result.content[1]
+        .raw
+        .as_text()
+        .map(|t| t.text.as_str())
+        .expect("Expected thumbnail label");
+    assert_eq!(label, "Thumbnail for result 2");
+    let image = result.content[2]
+        .raw
+        .as_image()
+        .expect("Expected image content");
+    assert_eq!(image.mime_type, "image/png");
+    assert!(!image.data.is_empty());
+
+    client.cancel().await.unwrap();
+}
+
+async fn call_search(
+    client: &RunningService<rmcp::RoleClient, impl rmcp::Service<rmcp::RoleClient>>,
+    query: &str,
+) -> rmcp::model::CallToolResult {
+    let request = CallToolRequestParams {
+        meta: None,
+        name: "search".into(),
+        arguments: Some(
+            serde_json::json!({ "query": query })
+                .as_object()
+                .unwrap()
+                .clone(),
+        ),
+        task: None,
+    };
+    client.call_tool(request).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_e2e_search_puts_answer_above_web_results() {
+    let html_mock = MockServer::start().await;
+    let api_mock = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .and(query_param("q", "rust programming"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results.html")),
+        )
+        .mount(&html_mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("q", "rust programming"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/instant_answer.json")),
+        )
+        .mount(&api_mock)
+        .await;
+
+    let client = setup_e2e(&html_mock, &api_mock).await;
+    let result = call_search(&client, "rust programming").await;
+    assert_eq!(result.is_error, Some(false));
+    let text = result
+        .content
+        .first()
+        .and_then(|c| c.raw.as_text())
+        .map(|t| t.text.as_str())
+        .expect("Expected text content");
+    let answer_at = text.find("## Answer for \"rust programming\"").unwrap();
+    let web_at = text
+        .find("## Web Search Results for \"rust programming\"")
+        .unwrap();
+    assert!(answer_at < web_at);
+    assert!(text.contains("### 1. The Rust Programming Language"));
+    assert!(!text.contains("**Warning:**"));
+
+    client.cancel().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_e2e_search_uses_configured_backend() {
+    let client = setup_e2e_with_backend("memory").await;
+    let result = call_search(&client, "rust").await;
+    assert_eq!(result.is_error, Some(false));
+    let text = result
+        .content
+        .first()
+        .and_then(|c| c.raw.as_text())
+        .map(|t| t.text.as_str())
+        .expect("Expected text content");
+    assert!(text.contains("### 1. In-memory result for rust"));
+    assert!(text.contains("_Source: In-Memory (1 results)_"));
+
+    client.cancel().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_e2e_search_tolerates_instant_answer_failure() {
+    let html_mock = MockServer::start().await;
+    let api_mock = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results.html")),
+        )
+        .mount(&html_mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("not json"))
+        .mount(&api_mock)
+        .await;
+
+    let client = setup_e2e(&html_mock, &api_mock).await;
+    let result = call_search(&client, "rust programming").await;
+    assert_eq!(result.is_error, Some(false));
+    let text = result
+        .content
+        .first()
+        .and_then(|c| c.raw.as_text())
+        .map(|t| t.text.as_str())
+        .expect("Expected text content");
+    assert!(!text.contains("## Answer for"));
+    assert!(text.contains("### 1. The Rust Programming Language"));
+    assert!(text.contains("> **Warning:** Instant answer failed: Failed to parse API response."));
+
+    client.cancel().await.unwrap();
+}
+
+async fn call_fetch_url(
+    client: &RunningService<rmcp::RoleClient, impl rmcp::Service<rmcp::RoleClient>>,
+    arguments: serde_json::Value,
+) -> rmcp::model::CallToolResult {
+    let request = CallToolRequestParams {
+        meta: None,
+        name: "fetch_url".into(),
+        arguments: Some(arguments.as_object().unwrap().clone()),
+        task: None,
+    };
+    client.call_tool(request).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_e2e_fetch_url_pages_through_cached_document() {
+    let page_mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/blog/rust"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(include_str!("fixtures/pages/article.html"), "text/html"),
+        )
+        .expect(1)
+        .mount(&page_mock)
+        .await;
+
+    let config = Config {
+        cache_ttl_secs: 300,
+        ..test_config()
+    };
+    let client = build_http_client(&config).unwrap();
+    let client = serve(Server::with_base_urls(
+        client,
+        config,
+        DuckDuckGoUrls::single_origin(&page_mock.uri()),
+    ))
+    .await;
+    let url = format!("{}/blog/rust", page_mock.uri());
+
+    let mut start_index = 0;
+    let mut pages = Vec::new();
+    loop {
+        let result = call_fetch_url(
+            &client,
+            serde_json::json!({ "url": url, "start_index": start_index, "max_length": 120 }),
+        )
+        .await;
+        assert_eq!(result.is_error, Some(false));
+        let text = result
+            .content
+            .first()
+            .and_then(|c| c.raw.as_text())
+            .map(|t| t.text.clone())
+            .expect("Expected text content");
+        assert!(text.starts_with("## Getting Started with Rust\n"));
+        let next = text
+            .split("continue at start_index=")
+            .nth(1)
+            .and_then(|rest| rest.split(' ').next())
+            .map(|n| n.parse::<usize>().unwrap());
+        pages.push(text);
+        match next {
+            Some(next) => {
+                assert_eq!(next, start_index + 120);
+                start_index = next;
+            }
+            None => break,
+        }
+    }
+    assert!(pages.len() > 2);
+    assert!(pages[0].contains("# Getting Started with Rust"));
+    assert!(pages.last().unwrap().contains("| nightly | daily |"));
+
+    // `max_chars` is still accepted as an alias of `max_length`.
+    let result = call_fetch_url(&client, serde_json::json!({ "url": url, "max_chars": 10 })).await;
+    let text = result
+        .content
+        .first()
+        .and_then(|c| c.raw.as_text())
+        .map(|t| t.text.as_str())
+        .expect("Expected text content");
+    assert!(text.contains("continue at start_index=10 "));
+
+    client.cancel().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_e2e_fetch_url_invalid_url_returns_error() {
+    let html_mock = MockServer::start().await;
+    let api_mock = MockServer::start().await;
+    let client = setup_e2e(&html_mock, &api_mock).await;
+
+    let result = call_fetch_url(&client, serde_json::json!({ "url": "ftp://example.com" })).await;
+    assert_eq!(result.is_error, Some(true));
 
     client.cancel().await.unwrap();
 }
@@ -186,3 +768,182 @@ async fn test_e2e_unknown_tool_returns_error() {
 
     client.cancel().await.unwrap();
 }
+
+#[tokio::test]
+async fn test_e2e_web_search_uses_injected_backend() {
+    let client = setup_e2e_with_backend("memory").await;
+
+    let result = client
+        .call_tool(web_search_request("rust programming"))
+        .await
+        .unwrap();
+
+    assert_eq!(result.is_error, Some(false));
+    let text = result
+        .content
+        .first()
+        .and_then(|c| c.raw.as_text())
+        .map(|t| t.text.as_str())
+        .expect("Expected text content");
+    assert!(text.contains("In-memory result for rust programming"));
+    assert!(text.contains("https://example.com/memory"));
+    assert!(text.contains("_Source: In-Memory (1 results)_"));
+
+    let structured = result
+        .structured_content
+        .expect("Expected structured content");
+    assert_eq!(structured["query"], "rust programming");
+    assert_eq!(structured["source"], "In-Memory");
+    assert_eq!(
+        structured["results"][0]["url"],
+        "https://example.com/memory"
+    );
+    assert_eq!(structured["warnings"], serde_json::json!([]));
+
+    client.cancel().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_e2e_web_search_warns_about_options_the_backend_ignores() {
+    let client = setup_e2e_with_backend("memory").await;
+
+    let result = client
+        .call_tool(CallToolRequestParams {
+            meta: None,
+            name: "web_search".into(),
+            arguments: Some(
+                serde_json::json!({ "query": "rust programming", "time_range": "week" })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+            ),
+            task: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(result.is_error, Some(false));
+    let text = result
+        .content
+        .first()
+        .and_then(|c| c.raw.as_text())
+        .map(|t| t.text.as_str())
+        .expect("Expected text content");
+    assert!(text.contains("In-memory result for rust programming"));
+    assert!(text.contains("> **Warning:** memory does not support time_range; it was ignored"));
+
+    client.cancel().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_e2e_web_search_fan_out_degrades_on_backend_failure() {
+    let client = setup_e2e_fan_out(vec![
+        Arc::new(InMemoryBackend),
+        Arc::new(FailingBackend("broken")),
+    ])
+    .await;
+
+    let result = client
+        .call_tool(fan_out_request("rust programming"))
+        .await
+        .unwrap();
+
+    assert_eq!(result.is_error, Some(false));
+    let text = result
+        .content
+        .first()
+        .and_then(|c| c.raw.as_text())
+        .map(|t| t.text.as_str())
+        .expect("Expected text content");
+    assert!(text.contains("In-memory result for rust programming"));
+    assert!(text.contains("**Found by:** memory\n"));
+    assert!(text.contains("> **Warning:** broken failed:"));
+    assert!(text.contains("_Source: In-Memory (1 results)_"));
+
+    let structured = result
+        .structured_content
+        .expect("Expected structured content");
+    assert_eq!(
+        structured["results"][0]["sources"],
+        serde_json::json!(["memory"])
+    );
+    assert!(structured["warnings"][0]
+        .as_str()
+        .is_some_and(|warning| warning.starts_with("broken failed:")));
+
+    client.cancel().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_e2e_web_search_fan_out_keeps_ads_out_of_max_results() {
+    let client =
+        setup_e2e_fan_out(vec![Arc::new(InMemoryBackend), Arc::new(SponsoredBackend)]).await;
+
+    let result = client
+        .call_tool(CallToolRequestParams {
+            meta: None,
+            name: "web_search".into(),
+            arguments: Some(
+                serde_json::json!({
+                    "query": "rust programming",
+                    "fan_out": true,
+                    "include_ads": true,
+                    "max_results": 2
+                })
+                .as_object()
+                .unwrap()
+                .clone(),
+            ),
+            task: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(result.is_error, Some(false));
+    let text = result
+        .content
+        .first()
+        .and_then(|c| c.raw.as_text())
+        .map(|t| t.text.as_str())
+        .expect("Expected text content");
+    assert!(text.contains("### 1. In-memory result for rust programming\n"));
+    assert!(text.contains("**Found by:** memory\n"));
+    assert!(text.contains("### 2. Organic result\n"));
+    assert!(text.contains(
+        "### Sponsored\n_Advertisements, not organic search results._\n\n- **[Sponsored memory](https://example.com/memory)**\n"
+    ));
+
+    client.cancel().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_e2e_web_search_fan_out_all_backends_failing_returns_error() {
+    let client = setup_e2e_fan_out(vec![
+        Arc::new(FailingBackend("broken")),
+        Arc::new(FailingBackend("offline")),
+    ])
+    .await;
+
+    let result = client
+        .call_tool(fan_out_request("rust programming"))
+        .await
+        .unwrap();
+
+    assert_eq!(result.is_error, Some(true));
+
+    client.cancel().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_e2e_web_search_unknown_backend_returns_error() {
+    let client = setup_e2e_with_backend("missing").await;
+
+    let result = client
+        .call_tool(web_search_request("rust programming"))
+        .await
+        .unwrap();
+
+    assert_eq!(result.is_error, Some(true));
+
+    client.cancel().await.unwrap();
+}
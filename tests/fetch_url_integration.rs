@@ -0,0 +1,370 @@
+use std::time::Duration;
+
+use reqwest::Client;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use websearch_mcp::error::WebSearchError;
+use websearch_mcp::tools::fetch_url::{
+    fetch_document, format_page_markdown, FetchedPage, MAX_DOCUMENT_BYTES,
+};
+
+fn build_test_client(timeout_secs: u64) -> Client {
+    Client::builder()
+        .timeout(Duration::from_secs(timeout_secs))
+        .build()
+        .unwrap()
+}
+
+async fn mount_page(server: &MockServer, route: &str, body: &str, content_type: &str) {
+    Mock::given(method("GET"))
+        .and(path(route))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body.to_string(), content_type))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_fetch_url_converts_article_to_markdown() {
+    let server = MockServer::start().await;
+    mount_page(
+        &server,
+        "/blog/rust",
+        include_str!("fixtures/pages/article.html"),
+        "text/html; charset=utf-8",
+    )
+    .await;
+
+    let url = format!("{}/blog/rust", server.uri());
+    let page = fetch_document(&build_test_client(10), &url, false, 10)
+        .await
+        .unwrap();
+
+    assert_eq!(page.title.as_deref(), Some("Getting Started with Rust"));
+    assert_eq!(page.url, url);
+    assert!(page.markdown.starts_with("# Getting Started with Rust"));
+    assert!(page.markdown.contains(&format!(
+        "Rust is a **fast** and _reliable_ language. Read the [official book]({}/book) first.",
+        server.uri()
+    )));
+    assert!(page
+        .markdown
+        .contains("## Installation\n\n1. Install `rustup`\n2. Run the installer"));
+    assert!(page
+        .markdown
+        .contains("```bash\ncurl https://sh.rustup.rs -sSf | sh\nrustc --version\n```"));
+    assert!(page
+        .markdown
+        .contains("| Channel | Cadence |\n|---|---|\n| stable | 6 weeks |\n| nightly | daily |"));
+}
+
+#[tokio::test]
+async fn test_fetch_url_strips_scripts_styles_and_nav() {
+    let server = MockServer::start().await;
+    mount_page(
+        &server,
+        "/blog/rust",
+        include_str!("fixtures/pages/article.html"),
+        "text/html",
+    )
+    .await;
+
+    let page = fetch_document(
+        &build_test_client(10),
+        &format!("{}/blog/rust", server.uri()),
+        false,
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert!(!page.markdown.contains("analytics"));
+    assert!(!page.markdown.contains("font-family"));
+    assert!(!page.markdown.contains("[Home]"));
+    assert!(!page.markdown.contains("[Blog]"));
+}
+
+#[tokio::test]
+async fn test_fetch_url_returns_plain_text_as_is() {
+    let server = MockServer::start().await;
+    mount_page(
+        &server,
+        "/robots.txt",
+        "User-agent: *\nDisallow:\n",
+        "text/plain",
+    )
+    .await;
+
+    let page = fetch_document(
+        &build_test_client(10),
+        &format!("{}/robots.txt", server.uri()),
+        false,
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(page.title, None);
+    assert_eq!(page.markdown, "User-agent: *\nDisallow:");
+}
+
+#[tokio::test]
+async fn test_fetch_url_rejects_unsupported_content_type() {
+    let server = MockServer::start().await;
+    mount_page(&server, "/file.pdf", "%PDF-1.7", "application/pdf").await;
+
+    let result = fetch_document(
+        &build_test_client(10),
+        &format!("{}/file.pdf", server.uri()),
+        false,
+        10,
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(WebSearchError::UnsupportedContentType(ref t)) if t == "application/pdf"
+    ));
+}
+
+#[tokio::test]
+async fn test_fetch_url_not_found_is_page_unavailable() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/missing"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let result = fetch_document(
+        &build_test_client(10),
+        &format!("{}/missing", server.uri()),
+        false,
+        10,
+    )
+    .await;
+
+    match result {
+        Err(e @ WebSearchError::PageUnavailable(404)) => assert!(!e.is_retryable()),
+        other => panic!("Expected PageUnavailable(404), got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_fetch_url_server_error_is_retryable() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/flaky"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+
+    let result = fetch_document(
+        &build_test_client(10),
+        &format!("{}/flaky", server.uri()),
+        false,
+        10,
+    )
+    .await;
+
+    assert!(result.unwrap_err().is_retryable());
+}
+
+#[tokio::test]
+async fn test_fetch_url_rejects_non_http_scheme() {
+    let result = fetch_document(&build_test_client(10), "file:///etc/passwd", false, 10).await;
+    assert!(matches!(result, Err(WebSearchError::InvalidUrl(_))));
+}
+
+#[tokio::test]
+async fn test_fetch_url_max_length_truncates_output() {
+    let server = MockServer::start().await;
+    mount_page(
+        &server,
+        "/blog/rust",
+        include_str!("fixtures/pages/article.html"),
+        "text/html",
+    )
+    .await;
+
+    let page = fetch_document(
+        &build_test_client(10),
+        &format!("{}/blog/rust", server.uri()),
+        false,
+        10,
+    )
+    .await
+    .unwrap();
+    let total = page.markdown.chars().count();
+    let output = format_page_markdown(&page, 0, 25);
+
+    assert!(output.contains(&format!(
+        "**Characters:** 0-25 of {}\n\n# Getting Started with Ru\n\n",
+        total
+    )));
+    assert!(output.ends_with(&format!(
+        "_Content truncated, continue at start_index=25 ({} characters remaining)._",
+        total - 25
+    )));
+
+    let next = format_page_markdown(&page, 25, 5);
+    assert!(next.contains(&format!(
+        "**Characters:** 25-30 of {}\n\nst\n\nR\n\n",
+        total
+    )));
+}
+
+async fn fetch_fixture(fixture: &str, extract_main_content: bool) -> FetchedPage {
+    let server = MockServer::start().await;
+    mount_page(&server, "/page", fixture, "text/html").await;
+    fetch_document(
+        &build_test_client(10),
+        &format!("{}/page", server.uri()),
+        extract_main_content,
+        10,
+    )
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_extract_main_content_news_layout() {
+    let news = include_str!("fixtures/pages/news.html");
+
+    let page = fetch_fixture(news, true).await;
+    assert_eq!(
+        page.title.as_deref(),
+        Some("Rust 1.80 released | Example News")
+    );
+    assert!(page
+        .markdown
+        .starts_with("# Rust 1.80 released with lazy cells\n\nBy Jane Doe\n\n"));
+    assert!(page
+        .markdown
+        .ends_with("the full list of changes is available in the release notes."));
+    assert!(!page.markdown.contains("cookies"));
+    assert!(!page.markdown.contains("Related stories"));
+    assert!(!page.markdown.contains("Copyright"));
+
+    let full = fetch_fixture(news, false).await;
+    assert!(full.markdown.contains("We use cookies"));
+    assert!(full.markdown.contains("Related stories"));
+}
+
+#[tokio::test]
+async fn test_extract_main_content_docs_layout() {
+    let page = fetch_fixture(include_str!("fixtures/pages/docs.html"), true).await;
+
+    assert!(page.markdown.starts_with("# Configuration\n\n"));
+    assert!(page.markdown.contains("## Precedence"));
+    assert!(page
+        .markdown
+        .contains("```toml\n[server]\nport = 8080\n```"));
+    assert!(page
+        .markdown
+        .ends_with("restart the process after editing the file."));
+    assert!(!page.markdown.contains("Introduction to the project"));
+    assert!(!page.markdown.contains("Edit this page"));
+}
+
+#[tokio::test]
+async fn test_extract_main_content_layout_without_semantic_tags() {
+    let page = fetch_fixture(include_str!("fixtures/pages/blog.html"), true).await;
+
+    assert!(page
+        .markdown
+        .starts_with("For years, the project ran on a managed Postgres instance"));
+    assert!(page
+        .markdown
+        .ends_with("the monthly bill dropped to almost nothing."));
+    assert!(!page.markdown.contains("Archive"));
+    assert!(!page.markdown.contains("Great post"));
+    assert!(!page.markdown.contains("A friend's blog"));
+}
+
+#[tokio::test]
+async fn test_extract_main_content_table_layout() {
+    let page = fetch_fixture(include_str!("fixtures/pages/legacy_table.html"), true).await;
+
+    assert!(page.markdown.starts_with("# Spring Edition\n\n"));
+    assert!(page
+        .markdown
+        .ends_with("| Date | Route |\n|---|---|\n| 12 April | Harbour to lighthouse |"));
+    assert!(!page.markdown.contains("Members area"));
+}
+
+#[tokio::test]
+async fn test_extract_main_content_falls_back_to_whole_page() {
+    let page = fetch_fixture(
+        "<html><body><h1>Short</h1><p>Too short to score.</p></body></html>",
+        true,
+    )
+    .await;
+
+    assert_eq!(page.markdown, "# Short\n\nToo short to score.");
+}
+
+#[tokio::test]
+async fn test_fetch_url_rejects_documents_over_the_size_limit() {
+    let server = MockServer::start().await;
+    let body = "a".repeat(MAX_DOCUMENT_BYTES + 1);
+    mount_page(&server, "/huge.txt", &body, "text/plain").await;
+
+    let result = fetch_document(
+        &build_test_client(10),
+        &format!("{}/huge.txt", server.uri()),
+        false,
+        10,
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(WebSearchError::DocumentTooLarge(MAX_DOCUMENT_BYTES))
+    ));
+}
+
+#[tokio::test]
+async fn test_fetch_url_stops_reading_chunked_body_over_the_size_limit() {
+    use tokio::io::AsyncWriteExt;
+
+    // A raw server that streams without Content-Length, so only the running total can stop it.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut request = [0u8; 1024];
+        let _ = tokio::io::AsyncReadExt::read(&mut socket, &mut request).await;
+        socket
+            .write_all(
+                b"HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nTransfer-Encoding: chunked\r\n\r\n",
+            )
+            .await
+            .unwrap();
+        let chunk = vec![b'a'; 64 * 1024];
+        let header = format!("{:x}\r\n", chunk.len());
+        // Keep sending well past the limit; the client is expected to hang up first.
+        for _ in 0..(MAX_DOCUMENT_BYTES / chunk.len() * 4) {
+            if socket.write_all(header.as_bytes()).await.is_err()
+                || socket.write_all(&chunk).await.is_err()
+                || socket.write_all(b"\r\n").await.is_err()
+            {
+                return;
+            }
+        }
+        let _ = socket.write_all(b"0\r\n\r\n").await;
+    });
+
+    let result = fetch_document(
+        &build_test_client(10),
+        &format!("http://{}/stream", addr),
+        false,
+        10,
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(WebSearchError::DocumentTooLarge(MAX_DOCUMENT_BYTES))
+    ));
+}
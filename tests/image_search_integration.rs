@@ -0,0 +1,318 @@
+use std::time::Duration;
+
+use reqwest::Client;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use websearch_mcp::error::WebSearchError;
+use websearch_mcp::models::images::{
+    ImageColor, ImageFilters, ImageLayout, ImageResult, ImageSize, ImageType,
+};
+use websearch_mcp::models::search::{SafeSearch, SearchOptions};
+use websearch_mcp::rate_limiter::RateLimiter;
+use websearch_mcp::tools::image_search::{fetch_thumbnail, fetch_thumbnails, search_images};
+
+const VQD: &str = "4-211998856789012345678901234567890";
+const PNG_BYTES: &[u8] = &[0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+
+fn build_test_client(timeout_secs: u64) -> Client {
+    Client::builder()
+        .timeout(Duration::from_secs(timeout_secs))
+        .build()
+        .unwrap()
+}
+
+fn build_test_rate_limiter() -> RateLimiter {
+    RateLimiter::new(Duration::from_millis(0))
+}
+
+fn options(max_results: usize) -> SearchOptions {
+    SearchOptions {
+        max_results,
+        ..Default::default()
+    }
+}
+
+fn image_with_thumbnail(thumbnail: String) -> ImageResult {
+    ImageResult {
+        title: "Rust Logo".to_string(),
+        image: "https://www.rust-lang.org/logo.png".to_string(),
+        thumbnail,
+        url: "https://www.rust-lang.org/".to_string(),
+        width: 512,
+        height: 512,
+        source: "Bing".to_string(),
+    }
+}
+
+async fn mount_vqd_page(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("q", "rust logo"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(include_str!("fixtures/vqd_page.html")),
+        )
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_image_search_returns_results() {
+    let server = MockServer::start().await;
+    mount_vqd_page(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/i.js"))
+        .and(query_param("q", "rust logo"))
+        .and(query_param("vqd", VQD))
+        .and(query_param("o", "json"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(include_str!("fixtures/image_results.json")),
+        )
+        .mount(&server)
+        .await;
+
+    let results = search_images(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "rust logo",
+        &options(2),
+        &ImageFilters::default(),
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(
+        results[0].image,
+        "https://www.rust-lang.org/logos/rust-logo-512x512.png"
+    );
+    assert_eq!((results[0].width, results[0].height), (1200, 1200));
+    assert_eq!(
+        results[0].thumbnail,
+        "https://tse1.mm.bing.net/th?id=OIP.rust1"
+    );
+}
+
+#[tokio::test]
+async fn test_image_search_sends_filters_region_and_safe_search() {
+    let server = MockServer::start().await;
+    mount_vqd_page(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/i.js"))
+        .and(query_param(
+            "f",
+            "size:Large,color:Monochrome,type:transparent,layout:Wide",
+        ))
+        .and(query_param("l", "jp-jp"))
+        .and(query_param("p", "-1"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(include_str!("fixtures/image_results.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let options = SearchOptions {
+        max_results: 10,
+        region: Some("jp-jp".to_string()),
+        safe_search: SafeSearch::Off,
+        ..Default::default()
+    };
+    let filters = ImageFilters {
+        size: Some(ImageSize::Large),
+        color: Some(ImageColor::Monochrome),
+        image_type: Some(ImageType::Transparent),
+        layout: Some(ImageLayout::Wide),
+    };
+    let results = search_images(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "rust logo",
+        &options,
+        &filters,
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 3);
+}
+
+#[tokio::test]
+async fn test_image_search_missing_vqd_returns_parse_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<html></html>"))
+        .mount(&server)
+        .await;
+
+    let result = search_images(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "rust logo",
+        &options(10),
+        &ImageFilters::default(),
+        10,
+    )
+    .await;
+
+    assert!(matches!(result, Err(WebSearchError::HtmlParseError(_))));
+}
+
+#[tokio::test]
+async fn test_image_search_empty_query() {
+    let server = MockServer::start().await;
+    let result = search_images(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "",
+        &options(10),
+        &ImageFilters::default(),
+        10,
+    )
+    .await;
+
+    assert!(matches!(result, Err(WebSearchError::EmptyQuery)));
+}
+
+#[tokio::test]
+async fn test_fetch_thumbnail_encodes_image_as_base64() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/thumb.png"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(PNG_BYTES.to_vec(), "image/png"))
+        .mount(&server)
+        .await;
+
+    let thumbnail = fetch_thumbnail(
+        &build_test_client(10),
+        &format!("{}/thumb.png", server.uri()),
+        1024,
+        10,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(thumbnail.mime_type, "image/png");
+    assert_eq!(thumbnail.data, "iVBORw0KGgo=");
+}
+
+#[tokio::test]
+async fn test_fetch_thumbnail_skips_oversized_image() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/big.png"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(vec![0u8; 2048], "image/png"))
+        .mount(&server)
+        .await;
+
+    let thumbnail = fetch_thumbnail(
+        &build_test_client(10),
+        &format!("{}/big.png", server.uri()),
+        1024,
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert!(thumbnail.is_none());
+}
+
+#[tokio::test]
+async fn test_fetch_thumbnail_stops_reading_stream_without_content_length() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut request = [0u8; 1024];
+        let _ = socket.read(&mut request).await;
+        socket
+            .write_all(
+                b"HTTP/1.1 200 OK\r\nContent-Type: image/png\r\nTransfer-Encoding: chunked\r\n\r\n",
+            )
+            .await
+            .unwrap();
+        // 64 MiB in total, far more than the cap; the client should hang up long before.
+        let chunk = vec![0u8; 64 * 1024];
+        for _ in 0..1024 {
+            if socket.write_all(b"10000\r\n").await.is_err()
+                || socket.write_all(&chunk).await.is_err()
+                || socket.write_all(b"\r\n").await.is_err()
+            {
+                return;
+            }
+        }
+        let _ = socket.write_all(b"0\r\n\r\n").await;
+    });
+
+    let thumbnail = fetch_thumbnail(
+        &build_test_client(10),
+        &format!("http://{}/stream.png", addr),
+        1024,
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert!(thumbnail.is_none());
+}
+
+#[tokio::test]
+async fn test_fetch_thumbnail_skips_non_image_content() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/page"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw("<html></html>", "text/html"))
+        .mount(&server)
+        .await;
+
+    let thumbnail = fetch_thumbnail(
+        &build_test_client(10),
+        &format!("{}/page", server.uri()),
+        1024,
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert!(thumbnail.is_none());
+}
+
+#[tokio::test]
+async fn test_fetch_thumbnails_keeps_order_and_tolerates_failures() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/ok.png"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(PNG_BYTES.to_vec(), "image/png"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/missing.png"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let results = vec![
+        image_with_thumbnail(format!("{}/missing.png", server.uri())),
+        image_with_thumbnail(String::new()),
+        image_with_thumbnail(format!("{}/ok.png", server.uri())),
+    ];
+    let thumbnails = fetch_thumbnails(&build_test_client(10), &results, 1024, 10).await;
+
+    assert_eq!(thumbnails.len(), 3);
+    assert!(thumbnails[0].is_none());
+    assert!(thumbnails[1].is_none());
+    assert_eq!(
+        thumbnails[2].as_ref().map(|t| t.mime_type.as_str()),
+        Some("image/png")
+    );
+}
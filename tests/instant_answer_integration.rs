@@ -4,6 +4,7 @@ use reqwest::Client;
 use wiremock::matchers::{method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
+use websearch_mcp::models::instant_answer::Disambiguation;
 use websearch_mcp::tools::instant_answer::execute_instant_answer;
 
 fn build_test_client(timeout_secs: u64) -> Client {
@@ -29,9 +30,15 @@ async fn test_instant_answer_returns_formatted_response() {
         .await;
 
     let client = build_test_client(10);
-    let result = execute_instant_answer(&client, &server.uri(), "rust programming", 10)
-        .await
-        .unwrap();
+    let result = execute_instant_answer(
+        &client,
+        &server.uri(),
+        "rust programming",
+        Disambiguation::Skip,
+        10,
+    )
+    .await
+    .unwrap();
 
     assert!(result.contains("Instant Answer for \"rust programming\""));
     assert!(result.contains("performance, type safety, and concurrency"));
@@ -52,7 +59,8 @@ async fn test_instant_answer_handles_server_error() {
         .await;
 
     let client = build_test_client(10);
-    let result = execute_instant_answer(&client, &server.uri(), "test", 10).await;
+    let result =
+        execute_instant_answer(&client, &server.uri(), "test", Disambiguation::Skip, 10).await;
 
     assert!(result.is_err());
     let err = result.unwrap_err();
@@ -75,7 +83,14 @@ async fn test_instant_answer_handles_timeout() {
         .await;
 
     let client = build_test_client(1);
-    let result = execute_instant_answer(&client, &server.uri(), "slow query", 1).await;
+    let result = execute_instant_answer(
+        &client,
+        &server.uri(),
+        "slow query",
+        Disambiguation::Skip,
+        1,
+    )
+    .await;
 
     assert!(result.is_err());
     let err = result.unwrap_err();
@@ -89,7 +104,7 @@ async fn test_instant_answer_handles_timeout() {
 async fn test_instant_answer_rejects_empty_query() {
     let server = MockServer::start().await;
     let client = build_test_client(10);
-    let result = execute_instant_answer(&client, &server.uri(), "", 10).await;
+    let result = execute_instant_answer(&client, &server.uri(), "", Disambiguation::Skip, 10).await;
 
     assert!(result.is_err());
     let err = result.unwrap_err();
@@ -115,9 +130,70 @@ async fn test_instant_answer_handles_empty_response() {
         .await;
 
     let client = build_test_client(10);
-    let result = execute_instant_answer(&client, &server.uri(), "xyzzy12345noresult", 10)
+    let result = execute_instant_answer(
+        &client,
+        &server.uri(),
+        "xyzzy12345noresult",
+        Disambiguation::Skip,
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert!(result.contains("No instant answer available"));
+}
+
+#[tokio::test]
+async fn test_instant_answer_answer_only_is_not_empty_result() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("q", "1 km in miles"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/instant_answer_answer.json")),
+        )
+        .mount(&server)
+        .await;
+
+    let client = build_test_client(10);
+    let result = execute_instant_answer(
+        &client,
+        &server.uri(),
+        "1 km in miles",
+        Disambiguation::Skip,
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert!(result.contains("### Answer\n1 kilometer = 0.621 miles"));
+    assert!(!result.contains("No instant answer available"));
+}
+
+#[tokio::test]
+async fn test_instant_answer_list_disambiguation_returns_numbered_options() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("q", "java"))
+        .and(query_param("skip_disambig", "0"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/instant_answer_disambig.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = build_test_client(10);
+    let result = execute_instant_answer(&client, &server.uri(), "java", Disambiguation::List, 10)
         .await
         .unwrap();
 
-    assert!(result.contains("No instant answer available"));
+    assert!(result.contains("**Response Type:** D (disambiguation)"));
+    assert!(result.contains("1. Java (programming language)"));
+    assert!(result.contains("**URL:** https://duckduckgo.com/Java_platform"));
 }
@@ -0,0 +1,171 @@
+use std::time::Duration;
+
+use reqwest::Client;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use websearch_mcp::error::WebSearchError;
+use websearch_mcp::models::search::{SafeSearch, SearchOptions, TimeRange};
+use websearch_mcp::rate_limiter::RateLimiter;
+use websearch_mcp::tools::news_search::{execute_news_search, search_news};
+
+const VQD: &str = "4-211998856789012345678901234567890";
+
+fn build_test_client(timeout_secs: u64) -> Client {
+    Client::builder()
+        .timeout(Duration::from_secs(timeout_secs))
+        .build()
+        .unwrap()
+}
+
+fn build_test_rate_limiter() -> RateLimiter {
+    RateLimiter::new(Duration::from_millis(0))
+}
+
+fn options(max_results: usize) -> SearchOptions {
+    SearchOptions {
+        max_results,
+        ..Default::default()
+    }
+}
+
+async fn mount_vqd_page(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("q", "rust"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(include_str!("fixtures/vqd_page.html")),
+        )
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_news_search_returns_results_newest_first() {
+    let server = MockServer::start().await;
+    mount_vqd_page(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/news.js"))
+        .and(query_param("q", "rust"))
+        .and(query_param("vqd", VQD))
+        .and(query_param("o", "json"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(include_str!("fixtures/news_results.json")),
+        )
+        .mount(&server)
+        .await;
+
+    let results = search_news(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "rust",
+        &options(10),
+        10,
+    )
+    .await
+    .unwrap();
+
+    let sources: Vec<&str> = results.iter().map(|r| r.source.as_str()).collect();
+    assert_eq!(sources, vec!["Ars Technica", "The Register", "LWN.net"]);
+}
+
+#[tokio::test]
+async fn test_news_search_sends_region_safe_search_and_time_range() {
+    let server = MockServer::start().await;
+    mount_vqd_page(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/news.js"))
+        .and(query_param("l", "jp-jp"))
+        .and(query_param("p", "1"))
+        .and(query_param("df", "w"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(include_str!("fixtures/news_results.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = execute_news_search(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "rust",
+        &SearchOptions {
+            max_results: 2,
+            region: Some("jp-jp".to_string()),
+            safe_search: SafeSearch::Strict,
+            time_range: Some(TimeRange::Week),
+            ..Default::default()
+        },
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert!(result.contains("### 1. White House doubles down on memory safety"));
+    assert!(result.contains("### 2. Rust 1.78 lands with diagnostic attributes"));
+    assert!(!result.contains("### 3."));
+    assert!(result.ends_with("_Source: DuckDuckGo News (2 results)_"));
+}
+
+#[tokio::test]
+async fn test_news_search_missing_vqd_is_parse_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<html></html>"))
+        .mount(&server)
+        .await;
+
+    let result = search_news(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "rust",
+        &options(10),
+        10,
+    )
+    .await;
+
+    assert!(matches!(result, Err(WebSearchError::HtmlParseError(_))));
+}
+
+#[tokio::test]
+async fn test_news_search_handles_server_error() {
+    let server = MockServer::start().await;
+    mount_vqd_page(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/news.js"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let result = search_news(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "rust",
+        &options(10),
+        10,
+    )
+    .await;
+
+    assert!(matches!(result, Err(WebSearchError::HttpError(_))));
+}
+
+#[tokio::test]
+async fn test_news_search_rejects_empty_query() {
+    let server = MockServer::start().await;
+    let result = search_news(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "",
+        &options(10),
+        10,
+    )
+    .await;
+
+    assert!(matches!(result, Err(WebSearchError::EmptyQuery)));
+}
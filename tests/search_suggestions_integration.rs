@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+use reqwest::Client;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use websearch_mcp::error::WebSearchError;
+use websearch_mcp::rate_limiter::RateLimiter;
+use websearch_mcp::tools::search_suggestions::fetch_suggestions;
+
+fn build_test_client(timeout_secs: u64) -> Client {
+    Client::builder()
+        .timeout(Duration::from_secs(timeout_secs))
+        .build()
+        .unwrap()
+}
+
+fn build_test_rate_limiter() -> RateLimiter {
+    RateLimiter::new(Duration::from_millis(0))
+}
+
+#[tokio::test]
+async fn test_suggestions_keep_rank_and_drop_duplicates() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/ac/"))
+        .and(query_param("q", "rus"))
+        .and(query_param("kl", "wt-wt"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(include_str!("fixtures/suggestions.json")),
+        )
+        .mount(&server)
+        .await;
+
+    let suggestions = fetch_suggestions(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "rus",
+        None,
+        10,
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        suggestions,
+        vec!["rust", "rust programming", "rust game", "rustic furniture"]
+    );
+}
+
+#[tokio::test]
+async fn test_suggestions_respect_max_results_and_region() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/ac/"))
+        .and(query_param("kl", "jp-jp"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(include_str!("fixtures/suggestions.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let suggestions = fetch_suggestions(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "rus",
+        Some("jp-jp"),
+        2,
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(suggestions, vec!["rust", "rust programming"]);
+}
+
+#[tokio::test]
+async fn test_suggestions_empty_response() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/ac/"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+        .mount(&server)
+        .await;
+
+    let suggestions = fetch_suggestions(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "xyzzy",
+        None,
+        10,
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert!(suggestions.is_empty());
+}
+
+#[tokio::test]
+async fn test_suggestions_invalid_json_returns_parse_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/ac/"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<html></html>"))
+        .mount(&server)
+        .await;
+
+    let result = fetch_suggestions(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "rus",
+        None,
+        10,
+        10,
+    )
+    .await;
+
+    assert!(matches!(result, Err(WebSearchError::JsonParseError(_))));
+}
+
+#[tokio::test]
+async fn test_suggestions_empty_query() {
+    let server = MockServer::start().await;
+    let result = fetch_suggestions(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "  ",
+        None,
+        10,
+        10,
+    )
+    .await;
+
+    assert!(matches!(result, Err(WebSearchError::EmptyQuery)));
+}
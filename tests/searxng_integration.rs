@@ -0,0 +1,225 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::Client;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use websearch_mcp::backends::searxng::SearxngBackend;
+use websearch_mcp::backends::SearchBackend;
+use websearch_mcp::error::WebSearchError;
+use websearch_mcp::models::search::{
+    format_response_markdown, SafeSearch, SearchOptions, TimeRange,
+};
+use websearch_mcp::rate_limiter::RateLimiter;
+
+fn build_backend(base_url: String, timeout_secs: u64) -> SearxngBackend {
+    let client = Client::builder()
+        .timeout(Duration::from_secs(timeout_secs))
+        .build()
+        .unwrap();
+    SearxngBackend::new(
+        client,
+        base_url,
+        Arc::new(RateLimiter::new(Duration::from_millis(0))),
+        timeout_secs,
+    )
+}
+
+fn options(max_results: usize) -> SearchOptions {
+    SearchOptions {
+        max_results,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_searxng_maps_results_infoboxes_and_suggestions() {
+    let server = MockServer::start().await;
+    let json = include_str!("fixtures/searxng_results.json");
+
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("q", "rust programming"))
+        .and(query_param("format", "json"))
+        .and(query_param("pageno", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(json))
+        .mount(&server)
+        .await;
+
+    let backend = build_backend(server.uri(), 10);
+    let response = backend
+        .search("rust programming", &options(3))
+        .await
+        .unwrap();
+
+    assert_eq!(response.results.len(), 3);
+    assert_eq!(response.results[0].url, "https://www.rust-lang.org/");
+    assert_eq!(
+        response.results[0].engines,
+        vec!["duckduckgo", "google", "brave"]
+    );
+    assert_eq!(response.results[2].snippet, "");
+    assert_eq!(response.infoboxes.len(), 1);
+    assert_eq!(response.infoboxes[0].title, "Rust");
+    assert_eq!(
+        response.suggestions,
+        vec!["rust book", "rust programming language"]
+    );
+}
+
+#[tokio::test]
+async fn test_searxng_markdown_output() {
+    let server = MockServer::start().await;
+    let json = include_str!("fixtures/searxng_results.json");
+
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("q", "rust programming"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(json))
+        .mount(&server)
+        .await;
+
+    let backend = build_backend(server.uri(), 10);
+    let response = backend
+        .search("rust programming", &options(3))
+        .await
+        .unwrap();
+    let markdown = format_response_markdown("rust programming", &response, backend.attribution());
+
+    assert!(markdown.contains("### Infobox: Rust"));
+    assert!(markdown.contains("- **Designed by:** Graydon Hoare"));
+    assert!(markdown.contains("**Engines:** duckduckgo, google, brave"));
+    assert!(markdown.contains("### Suggestions"));
+    assert!(markdown.contains("_Source: SearXNG (3 results)_"));
+}
+
+#[tokio::test]
+async fn test_searxng_sends_filters() {
+    let server = MockServer::start().await;
+    let json = include_str!("fixtures/searxng_results.json");
+
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("q", "rust site:rust-lang.org"))
+        .and(query_param("pageno", "2"))
+        .and(query_param("language", "ja-JP"))
+        .and(query_param("safesearch", "2"))
+        .and(query_param("time_range", "month"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(json))
+        .mount(&server)
+        .await;
+
+    let backend = build_backend(server.uri(), 10);
+    let response = backend
+        .search(
+            "rust",
+            &SearchOptions {
+                max_results: 2,
+                page: 2,
+                region: Some("jp-jp".to_string()),
+                safe_search: SafeSearch::Strict,
+                time_range: Some(TimeRange::Month),
+                include_domains: vec!["rust-lang.org".to_string()],
+                exclude_domains: Vec::new(),
+                include_ads: false,
+            },
+        )
+        .await
+        .unwrap();
+
+    let urls: Vec<&str> = response.results.iter().map(|r| r.url.as_str()).collect();
+    assert_eq!(
+        urls,
+        vec![
+            "https://www.rust-lang.org/",
+            "https://doc.rust-lang.org/book/"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_searxng_walks_pages_until_max_results() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("pageno", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/searxng_results.json")),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("pageno", "2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/searxng_results_page2.json")),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("pageno", "3"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(r#"{"query": "rust programming", "results": []}"#),
+        )
+        .mount(&server)
+        .await;
+
+    let backend = build_backend(server.uri(), 10);
+    let response = backend
+        .search("rust programming", &options(10))
+        .await
+        .unwrap();
+
+    assert_eq!(response.results.len(), 4);
+    assert_eq!(response.results[3].url, "https://crates.io/");
+    assert_eq!(response.infoboxes.len(), 1);
+}
+
+#[tokio::test]
+async fn test_searxng_json_disabled_returns_http_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(403))
+        .mount(&server)
+        .await;
+
+    let backend = build_backend(server.uri(), 10);
+    let result = backend.search("rust", &options(10)).await;
+
+    assert!(matches!(result, Err(WebSearchError::HttpError(_))));
+}
+
+#[tokio::test]
+async fn test_searxng_invalid_json_returns_parse_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<html></html>"))
+        .mount(&server)
+        .await;
+
+    let backend = build_backend(server.uri(), 10);
+    let result = backend.search("rust", &options(10)).await;
+
+    assert!(matches!(result, Err(WebSearchError::JsonParseError(_))));
+}
+
+#[tokio::test]
+async fn test_searxng_rejects_empty_query() {
+    let server = MockServer::start().await;
+    let backend = build_backend(server.uri(), 10);
+    let result = backend.search("", &options(10)).await;
+
+    assert!(matches!(result, Err(WebSearchError::EmptyQuery)));
+}
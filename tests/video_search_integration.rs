@@ -0,0 +1,219 @@
+use std::time::Duration;
+
+use reqwest::Client;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use websearch_mcp::error::WebSearchError;
+use websearch_mcp::models::search::{SafeSearch, SearchOptions};
+use websearch_mcp::models::videos::{VideoDuration, VideoFilters, VideoResolution};
+use websearch_mcp::rate_limiter::RateLimiter;
+use websearch_mcp::tools::video_search::{execute_video_search, search_videos};
+
+const VQD: &str = "4-211998856789012345678901234567890";
+
+fn build_test_client(timeout_secs: u64) -> Client {
+    Client::builder()
+        .timeout(Duration::from_secs(timeout_secs))
+        .build()
+        .unwrap()
+}
+
+fn build_test_rate_limiter() -> RateLimiter {
+    RateLimiter::new(Duration::from_millis(0))
+}
+
+fn options(max_results: usize) -> SearchOptions {
+    SearchOptions {
+        max_results,
+        ..Default::default()
+    }
+}
+
+async fn mount_vqd_page(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("q", "rust tutorial"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(include_str!("fixtures/vqd_page.html")),
+        )
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_video_search_returns_metadata() {
+    let server = MockServer::start().await;
+    mount_vqd_page(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/v.js"))
+        .and(query_param("q", "rust tutorial"))
+        .and(query_param("vqd", VQD))
+        .and(query_param("o", "json"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(include_str!("fixtures/video_results.json")),
+        )
+        .mount(&server)
+        .await;
+
+    let results = search_videos(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "rust tutorial",
+        &options(2),
+        &VideoFilters::default(),
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[1].title, "Rust in 100 Seconds");
+    assert_eq!(results[1].duration, "2:29");
+    assert_eq!(results[1].publisher, "YouTube");
+    assert_eq!(results[1].upload_date(), Some("2021-09-21"));
+    assert_eq!(results[1].statistics.view_count, Some(2873001));
+    assert_eq!(
+        results[1].embed_url,
+        "https://www.youtube.com/embed/5C_HPTJg5ek?autoplay=1"
+    );
+}
+
+#[tokio::test]
+async fn test_video_search_sends_filters_region_and_safe_search() {
+    let server = MockServer::start().await;
+    mount_vqd_page(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/v.js"))
+        .and(query_param(
+            "f",
+            ",videoDefinition:high,videoDuration:long,",
+        ))
+        .and(query_param("l", "jp-jp"))
+        .and(query_param("p", "-1"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(include_str!("fixtures/video_results.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let options = SearchOptions {
+        max_results: 10,
+        region: Some("jp-jp".to_string()),
+        safe_search: SafeSearch::Off,
+        ..Default::default()
+    };
+    let filters = VideoFilters {
+        duration: Some(VideoDuration::Long),
+        resolution: Some(VideoResolution::High),
+    };
+    let results = search_videos(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "rust tutorial",
+        &options,
+        &filters,
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 3);
+}
+
+#[tokio::test]
+async fn test_execute_video_search_formats_markdown() {
+    let server = MockServer::start().await;
+    mount_vqd_page(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/v.js"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(include_str!("fixtures/video_results.json")),
+        )
+        .mount(&server)
+        .await;
+
+    let markdown = execute_video_search(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "rust tutorial",
+        &options(10),
+        &VideoFilters::default(),
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert!(markdown.contains("## Video Results for \"rust tutorial\""));
+    assert!(markdown.contains("**Publisher:** YouTube (Fireship)"));
+    assert!(markdown.contains("**Views:** 2873001"));
+    assert!(markdown.ends_with("_Source: DuckDuckGo Videos (3 results)_"));
+}
+
+#[tokio::test]
+async fn test_video_search_missing_vqd_returns_parse_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<html></html>"))
+        .mount(&server)
+        .await;
+
+    let result = search_videos(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "rust tutorial",
+        &options(10),
+        &VideoFilters::default(),
+        10,
+    )
+    .await;
+
+    assert!(matches!(result, Err(WebSearchError::HtmlParseError(_))));
+}
+
+#[tokio::test]
+async fn test_video_search_server_error() {
+    let server = MockServer::start().await;
+    mount_vqd_page(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/v.js"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let result = search_videos(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "rust tutorial",
+        &options(10),
+        &VideoFilters::default(),
+        10,
+    )
+    .await;
+
+    assert!(matches!(result, Err(WebSearchError::HttpError(_))));
+}
+
+#[tokio::test]
+async fn test_video_search_empty_query() {
+    let server = MockServer::start().await;
+    let result = search_videos(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        "",
+        &options(10),
+        &VideoFilters::default(),
+        10,
+    )
+    .await;
+
+    assert!(matches!(result, Err(WebSearchError::EmptyQuery)));
+}
@@ -1,10 +1,15 @@
+use std::sync::Arc;
 use std::time::Duration;
 
 use reqwest::Client;
-use wiremock::matchers::{method, path, query_param};
+use wiremock::matchers::{body_string_contains, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
-use websearch_mcp::tools::web_search::execute_web_search;
+use websearch_mcp::backends::duckduckgo::DuckDuckGoBackend;
+use websearch_mcp::backends::SearchBackend;
+use websearch_mcp::models::search::{SafeSearch, SearchOptions, TimeRange};
+use websearch_mcp::rate_limiter::RateLimiter;
+use websearch_mcp::tools::web_search::{execute_web_search, search_web};
 
 fn build_test_client(timeout_secs: u64) -> Client {
     Client::builder()
@@ -13,6 +18,40 @@ fn build_test_client(timeout_secs: u64) -> Client {
         .unwrap()
 }
 
+fn build_test_rate_limiter() -> RateLimiter {
+    RateLimiter::new(Duration::from_millis(0))
+}
+
+fn options(max_results: usize) -> SearchOptions {
+    SearchOptions {
+        max_results,
+        ..Default::default()
+    }
+}
+
+async fn mount_paginated_results(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .and(query_param("q", "rust programming"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results_page1.html")),
+        )
+        .mount(server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/html/"))
+        .and(body_string_contains("s=3"))
+        .and(body_string_contains("vqd=4-123456789012345678901234567890"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results_page2.html")),
+        )
+        .mount(server)
+        .await;
+}
+
 #[tokio::test]
 async fn test_web_search_returns_formatted_results() {
     let server = MockServer::start().await;
@@ -26,9 +65,17 @@ async fn test_web_search_returns_formatted_results() {
         .await;
 
     let client = build_test_client(10);
-    let result = execute_web_search(&client, &server.uri(), "rust programming", 10, 10)
-        .await
-        .unwrap();
+    let result = execute_web_search(
+        &client,
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust programming",
+        &options(10),
+        10,
+    )
+    .await
+    .unwrap();
 
     assert!(result.contains("Web Search Results for \"rust programming\""));
     assert!(result.contains("The Rust Programming Language"));
@@ -48,7 +95,16 @@ async fn test_web_search_handles_server_error() {
         .await;
 
     let client = build_test_client(10);
-    let result = execute_web_search(&client, &server.uri(), "test", 10, 10).await;
+    let result = execute_web_search(
+        &client,
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "test",
+        &options(10),
+        10,
+    )
+    .await;
 
     assert!(result.is_err());
     let err = result.unwrap_err();
@@ -70,7 +126,16 @@ async fn test_web_search_handles_timeout() {
         .await;
 
     let client = build_test_client(1);
-    let result = execute_web_search(&client, &server.uri(), "slow query", 10, 1).await;
+    let result = execute_web_search(
+        &client,
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "slow query",
+        &options(10),
+        1,
+    )
+    .await;
 
     assert!(result.is_err());
     let err = result.unwrap_err();
@@ -84,7 +149,16 @@ async fn test_web_search_handles_timeout() {
 async fn test_web_search_rejects_empty_query() {
     let server = MockServer::start().await;
     let client = build_test_client(10);
-    let result = execute_web_search(&client, &server.uri(), "", 10, 10).await;
+    let result = execute_web_search(
+        &client,
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "",
+        &options(10),
+        10,
+    )
+    .await;
 
     assert!(result.is_err());
     let err = result.unwrap_err();
@@ -107,9 +181,802 @@ async fn test_web_search_handles_empty_results() {
         .await;
 
     let client = build_test_client(10);
-    let result = execute_web_search(&client, &server.uri(), "xyzzy12345noresult", 10, 10)
+    let result = execute_web_search(
+        &client,
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "xyzzy12345noresult",
+        &options(10),
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert!(result.contains("No results found."));
+}
+
+#[tokio::test]
+async fn test_web_search_decodes_redirect_urls() {
+    let server = MockServer::start().await;
+    let html = include_str!("fixtures/search_results_redirect.html");
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .and(query_param("q", "rust programming"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(html))
+        .mount(&server)
+        .await;
+
+    let client = build_test_client(10);
+    let result = execute_web_search(
+        &client,
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust programming",
+        &options(10),
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert!(result.contains("**URL:** https://www.rust-lang.org/"));
+    assert!(result.contains("**URL:** https://doc.rust-lang.org/book/"));
+    assert!(!result.contains("duckduckgo.com/l/"));
+}
+
+#[tokio::test]
+async fn test_web_search_walks_next_page_form() {
+    let server = MockServer::start().await;
+    mount_paginated_results(&server).await;
+
+    let client = build_test_client(10);
+    let results = search_web(
+        &client,
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust programming",
+        &options(5),
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 5);
+    assert_eq!(results[0].url, "https://www.rust-lang.org/");
+    assert_eq!(results[3].url, "https://crates.io/");
+    assert_eq!(results[4].url, "https://github.com/rust-lang/rust");
+}
+
+#[tokio::test]
+async fn test_web_search_stops_when_first_page_is_enough() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .and(query_param("q", "rust programming"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results_page1.html")),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/html/"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = build_test_client(10);
+    let results = search_web(
+        &client,
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust programming",
+        &options(3),
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 3);
+}
+
+#[tokio::test]
+async fn test_web_search_returns_requested_page() {
+    let server = MockServer::start().await;
+    mount_paginated_results(&server).await;
+
+    let client = build_test_client(10);
+    let result = execute_web_search(
+        &client,
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust programming",
+        &SearchOptions {
+            page: 2,
+            ..Default::default()
+        },
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert!(result.contains("### 1. crates.io: Rust Package Registry"));
+    assert!(result.contains("### 2. GitHub - rust-lang/rust"));
+    assert!(!result.contains("www.rust-lang.org"));
+}
+
+#[tokio::test]
+async fn test_web_search_page_past_last_returns_no_results() {
+    let server = MockServer::start().await;
+    mount_paginated_results(&server).await;
+
+    let client = build_test_client(10);
+    let results = search_web(
+        &client,
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust programming",
+        &SearchOptions {
+            page: 3,
+            ..Default::default()
+        },
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert!(results.is_empty());
+}
+
+#[tokio::test]
+async fn test_web_search_rejects_page_beyond_limit() {
+    let server = MockServer::start().await;
+    let result = search_web(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust programming",
+        &SearchOptions {
+            page: 11,
+            ..Default::default()
+        },
+        10,
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(websearch_mcp::error::WebSearchError::PageOutOfRange(11))
+    ));
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_web_search_sends_region_and_safe_search() {
+    let server = MockServer::start().await;
+    let html = include_str!("fixtures/search_results.html");
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .and(query_param("q", "rust programming"))
+        .and(query_param("kl", "jp-jp"))
+        .and(query_param("kp", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(html))
+        .mount(&server)
+        .await;
+
+    let client = build_test_client(10);
+    let results = search_web(
+        &client,
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust programming",
+        &SearchOptions {
+            region: Some("jp-jp".to_string()),
+            safe_search: SafeSearch::Strict,
+            ..Default::default()
+        },
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 3);
+}
+
+#[tokio::test]
+async fn test_web_search_carries_filters_into_next_page() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .and(query_param("q", "rust programming"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results_page1.html")),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/html/"))
+        .and(body_string_contains("kl=jp-jp"))
+        .and(body_string_contains("kp=-2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results_page2.html")),
+        )
+        .mount(&server)
+        .await;
+
+    let client = build_test_client(10);
+    let results = search_web(
+        &client,
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust programming",
+        &SearchOptions {
+            max_results: 5,
+            region: Some("jp-jp".to_string()),
+            safe_search: SafeSearch::Off,
+            ..Default::default()
+        },
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 5);
+}
+
+#[tokio::test]
+async fn test_web_search_sends_time_range() {
+    let server = MockServer::start().await;
+    let html = include_str!("fixtures/search_results.html");
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .and(query_param("q", "rust release notes"))
+        .and(query_param("df", "w"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(html))
+        .mount(&server)
+        .await;
+
+    let client = build_test_client(10);
+    let results = search_web(
+        &client,
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust release notes",
+        &SearchOptions {
+            time_range: Some(TimeRange::Week),
+            ..Default::default()
+        },
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 3);
+}
+
+#[tokio::test]
+async fn test_web_search_include_domains_adds_site_operators_and_filters() {
+    let server = MockServer::start().await;
+    let html = include_str!("fixtures/search_results_mixed_domains.html");
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .and(query_param("q", "serde (site:docs.rs OR site:github.com)"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(html))
+        .mount(&server)
+        .await;
+
+    let client = build_test_client(10);
+    let results = search_web(
+        &client,
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "serde",
+        &SearchOptions {
+            include_domains: vec!["docs.rs".to_string(), "github.com".to_string()],
+            ..Default::default()
+        },
+        10,
+    )
+    .await
+    .unwrap();
+
+    let urls: Vec<&str> = results.iter().map(|r| r.url.as_str()).collect();
+    assert_eq!(
+        urls,
+        vec![
+            "https://docs.rs/serde/latest/serde/",
+            "https://github.com/serde-rs/serde"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_web_search_exclude_domains_filters_subdomains() {
+    let server = MockServer::start().await;
+    let html = include_str!("fixtures/search_results_mixed_domains.html");
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .and(query_param("q", "serde -site:pinterest.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(html))
+        .mount(&server)
+        .await;
+
+    let client = build_test_client(10);
+    let results = search_web(
+        &client,
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "serde",
+        &SearchOptions {
+            exclude_domains: vec!["pinterest.com".to_string()],
+            ..Default::default()
+        },
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 3);
+    assert!(results.iter().all(|r| !r.url.contains("pinterest")));
+}
+
+#[tokio::test]
+async fn test_duckduckgo_backend_searches_html_endpoint() {
+    let server = MockServer::start().await;
+    mount_paginated_results(&server).await;
+
+    let backend = DuckDuckGoBackend::new(
+        build_test_client(10),
+        server.uri(),
+        server.uri(),
+        Arc::new(build_test_rate_limiter()),
+        10,
+    );
+    let response = backend
+        .search("rust programming", &options(4))
         .await
         .unwrap();
 
-    assert!(result.contains("No results found."));
+    assert_eq!(backend.name(), "duckduckgo");
+    assert!(backend.capabilities().pagination);
+    assert_eq!(response.results.len(), 4);
+    assert_eq!(response.results[3].url, "https://crates.io/");
+}
+
+#[tokio::test]
+async fn test_web_search_falls_back_to_lite_on_server_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/lite/"))
+        .and(query_param("q", "rust programming"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results_lite.html")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let results = search_web(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust programming",
+        &options(3),
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].url, "https://www.rust-lang.org/");
+}
+
+#[tokio::test]
+async fn test_web_search_falls_back_to_lite_on_changed_markup() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results_changed_markup.html")),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/lite/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results_lite.html")),
+        )
+        .mount(&server)
+        .await;
+
+    let result = execute_web_search(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust programming",
+        &options(2),
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert!(result.contains("Rust (programming language) - Wikipedia"));
+    assert!(result.contains("_Source: DuckDuckGo (2 results)_"));
+}
+
+#[tokio::test]
+async fn test_web_search_lite_fallback_walks_next_page_form() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/lite/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results_lite.html")),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/lite/"))
+        .and(body_string_contains("s=23"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results_lite.html")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let results = search_web(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust programming",
+        &options(4),
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 4);
+}
+
+#[tokio::test]
+async fn test_web_search_reports_primary_error_when_lite_also_fails() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results_changed_markup.html")),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/lite/"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let result = search_web(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust programming",
+        &options(10),
+        10,
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(websearch_mcp::error::WebSearchError::HtmlParseError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_web_search_reports_parse_error_when_both_layouts_changed() {
+    let server = MockServer::start().await;
+    let changed = include_str!("fixtures/search_results_changed_markup.html");
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(changed))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/lite/"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(changed))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = search_web(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust programming",
+        &options(10),
+        10,
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(websearch_mcp::error::WebSearchError::HtmlParseError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_web_search_detects_block_page() {
+    let server = MockServer::start().await;
+    let blocked = include_str!("fixtures/search_results_blocked.html");
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(blocked))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/lite/"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(blocked))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = execute_web_search(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust programming",
+        &options(10),
+        10,
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(websearch_mcp::error::WebSearchError::Blocked(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_web_search_snippet_quoting_block_markers_is_not_blocked() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results_marker_snippet.html")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let results = search_web(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "duckduckgo anomaly",
+        &options(10),
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].url, "https://example.com/ddg-bot-detection");
+}
+
+#[tokio::test]
+async fn test_web_search_treats_202_and_403_as_blocked() {
+    for status in [202, 403] {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/html/"))
+            .respond_with(ResponseTemplate::new(status))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/lite/"))
+            .respond_with(ResponseTemplate::new(status))
+            .mount(&server)
+            .await;
+
+        let result = search_web(
+            &build_test_client(10),
+            &build_test_rate_limiter(),
+            &server.uri(),
+            &server.uri(),
+            "rust programming",
+            &options(10),
+            10,
+        )
+        .await;
+
+        assert!(
+            matches!(
+                result,
+                Err(websearch_mcp::error::WebSearchError::Blocked(_))
+            ),
+            "status {status} should be reported as blocked"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_web_search_block_on_html_recovers_via_lite() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results_blocked.html")),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/lite/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results_lite.html")),
+        )
+        .mount(&server)
+        .await;
+
+    let results = search_web(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust programming",
+        &options(3),
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 3);
+}
+
+#[tokio::test]
+async fn test_web_search_excludes_ads_by_default() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results_ads.html")),
+        )
+        .mount(&server)
+        .await;
+
+    let result = execute_web_search(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust hosting",
+        &options(10),
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert!(!result.contains("Managed Rust Hosting"));
+    assert!(!result.contains("### Sponsored"));
+    assert!(result.contains("### 1. The Rust Programming Language"));
+    assert!(result.contains("_Source: DuckDuckGo (2 results)_"));
+}
+
+#[tokio::test]
+async fn test_web_search_include_ads_renders_sponsored_section() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/html/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("fixtures/search_results_ads.html")),
+        )
+        .mount(&server)
+        .await;
+
+    let result = execute_web_search(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust hosting",
+        &SearchOptions {
+            max_results: 1,
+            include_ads: true,
+            ..Default::default()
+        },
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert!(result.contains("### 1. The Rust Programming Language"));
+    assert!(!result.contains("### 2."));
+    let sponsored = result.split("### Sponsored").nth(1).unwrap();
+    assert!(sponsored.contains("Managed Rust Hosting - Deploy in Seconds"));
+    assert!(sponsored.contains("Rust Course - 90% Off Today"));
+    assert!(result.contains("_Source: DuckDuckGo (1 results)_"));
+}
+
+#[tokio::test]
+async fn test_web_search_positions_continue_across_pages() {
+    let server = MockServer::start().await;
+    mount_paginated_results(&server).await;
+
+    let results = search_web(
+        &build_test_client(10),
+        &build_test_rate_limiter(),
+        &server.uri(),
+        &server.uri(),
+        "rust programming",
+        &SearchOptions {
+            page: 2,
+            ..Default::default()
+        },
+        10,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results[0].position, Some(4));
+    assert_eq!(results[0].domain.as_deref(), Some("crates.io"));
+    assert_eq!(results[1].position, Some(5));
 }